@@ -178,22 +178,14 @@ fn main() {
 fn next_generation(grid: &mut Grid<LifeStage>) {
     use LifeStage::*;
 
-    let next_stage: Vec<_> = (0..grid.size())
-        .map(|i| {
-            let neighbors = grid.all_around_neighbors(i).unwrap();
-            let count = neighbors
-                .iter()
-                .filter(|cell| *cell == &Some(&Alive))
-                .count();
-            match grid.get(i).unwrap() {
-                Dead if count == 3 => Alive,
-                Alive if count == 2 || count == 3 => Alive,
-                _ => Dead,
-            }
-        })
-        .collect();
-
-    for (grid, next) in grid.iter_mut().zip(next_stage.into_iter()) {
-        *grid = next;
-    }
+    grid.step(|grid, coord, stage| {
+        let count = grid
+            .neighbor_count(coord, true, |cell| *cell == Alive)
+            .unwrap();
+        match stage {
+            Dead if count == 3 => Alive,
+            Alive if count == 2 || count == 3 => Alive,
+            _ => Dead,
+        }
+    });
 }
@@ -0,0 +1,188 @@
+use crate::error::GridError;
+use crate::grid::{Grid, GridOptions};
+use crate::index::Index;
+use crate::intogrid::row_col_length_check;
+
+/// How the cells of a `GridBuilder` are created
+enum Fill<'a, T> {
+    Unset,
+    Value(T, fn(&T) -> T),
+    Default(fn() -> T),
+    Func(Box<dyn FnMut((isize, isize)) -> T + 'a>),
+}
+
+/// Builds a `Grid` of a given size without first collecting the cells into a `Vec`.  One of `filled`, `filled_default` or `from_fn` sets the
+/// value of every cell, and `build` creates the grid.
+/// ```
+/// use neighborgrid::*;
+/// let grid = GridBuilder::new(3, 2)
+///     .options(GridOptions::default().with_origin(Origin::LowerLeft).with_inverted_y(false))
+///     .from_fn(|(x, y)| x + y * 10)
+///     .build()
+///     .expect("invalid size");
+/// assert_eq!(grid.to_2d_vec(), vec![vec![10, 11, 12], vec![0, 1, 2]]);
+/// ```
+pub struct GridBuilder<'a, T> {
+    cols: usize,
+    rows: usize,
+    options: GridOptions,
+    fill: Fill<'a, T>,
+}
+
+impl<'a, T> GridBuilder<'a, T> {
+    /// Starts a builder for a grid with `cols` columns and `rows` rows, and the default `GridOptions`
+    pub fn new(cols: usize, rows: usize) -> Self {
+        GridBuilder {
+            cols,
+            rows,
+            options: GridOptions::default(),
+            fill: Fill::Unset,
+        }
+    }
+
+    /// Sets the `GridOptions` of the grid.  The coordinates passed to a `from_fn` function are based on these options.
+    pub fn options(self, options: GridOptions) -> Self {
+        GridBuilder { options, ..self }
+    }
+
+    /// Sets every cell to a clone of `value`
+    pub fn filled(self, value: T) -> Self
+    where
+        T: Clone,
+    {
+        GridBuilder {
+            fill: Fill::Value(value, T::clone),
+            ..self
+        }
+    }
+
+    /// Sets every cell to the default value of `T`
+    pub fn filled_default(self) -> Self
+    where
+        T: Default,
+    {
+        GridBuilder {
+            fill: Fill::Default(T::default),
+            ..self
+        }
+    }
+
+    /// Sets each cell to the result of calling `f` with the coordinate of the cell.  The cells are created in row major order.  `f` may borrow
+    /// from its environment, such as to look up each cell in another grid.
+    pub fn from_fn<F: FnMut((isize, isize)) -> T + 'a>(self, f: F) -> Self {
+        GridBuilder {
+            fill: Fill::Func(Box::new(f)),
+            ..self
+        }
+    }
+
+    /// Creates the grid.  Returns `InvalidSize` if either dimension is zero or the value of the cells was never set, and `ExcessiveSize` if the grid
+    /// would be too large.
+    pub fn build(self) -> Result<Grid<T>, GridError> {
+        let total = row_col_length_check(self.rows, self.cols)?;
+        if total == 0 {
+            return Err(GridError::InvalidSize);
+        }
+        let mut grid = Grid::create(
+            Vec::with_capacity(total),
+            self.rows,
+            self.cols,
+            Some(self.options),
+        );
+        match self.fill {
+            Fill::Unset => return Err(GridError::InvalidSize),
+            Fill::Value(value, clone) => {
+                grid.items.extend((1..total).map(|_| clone(&value)));
+                grid.items.push(value);
+            }
+            Fill::Default(default) => grid.items.extend((0..total).map(|_| default())),
+            Fill::Func(mut f) => {
+                for i in 0..total {
+                    let coord = Index::output(i, &grid);
+                    grid.items.push(f(coord));
+                }
+            }
+        }
+        Ok(grid)
+    }
+}
+
+#[cfg(test)]
+mod builder_tests {
+    use super::*;
+    use crate::grid::Origin;
+
+    #[test]
+    fn should_build_filled_grid() {
+        let grid = GridBuilder::new(4, 3).filled('.').build().unwrap();
+        assert_eq!(grid.columns(), 4);
+        assert_eq!(grid.rows(), 3);
+        assert!(grid.iter().all(|c| *c == '.'));
+        assert_eq!(grid.options, GridOptions::default());
+
+        let grid: Grid<String> = GridBuilder::new(2, 2).filled_default().build().unwrap();
+        assert_eq!(grid.to_2d_vec(), vec![vec![String::new(); 2]; 2]);
+    }
+
+    #[test]
+    fn should_build_from_fn() {
+        let options = GridOptions::default()
+            .with_origin(Origin::Center)
+            .with_inverted_y(false);
+        let grid = GridBuilder::new(3, 5)
+            .options(options.clone())
+            .from_fn(|(x, y)| (x, y))
+            .build()
+            .unwrap();
+        assert_eq!(grid.options, options);
+        for (coord, cell) in grid.coordinates().zip(grid.iter()) {
+            assert_eq!(coord, *cell);
+        }
+        assert_eq!(grid.get((0, 0)), Some(&(0, 0)));
+
+        let mut count = 0;
+        let grid = GridBuilder::new(3, 2)
+            .from_fn(move |_| {
+                count += 1;
+                count
+            })
+            .build()
+            .unwrap();
+        assert_eq!(grid.to_2d_vec(), vec![vec![1, 2, 3], vec![4, 5, 6]]);
+    }
+
+    #[test]
+    fn should_build_from_borrowing_fn() {
+        let source = Grid::new_from_1d((0..6).collect(), 3, 2, None).unwrap();
+        let mut visited = Vec::new();
+        let grid = GridBuilder::new(3, 2)
+            .from_fn(|coord| {
+                visited.push(coord);
+                source.get(coord).copied().unwrap_or_default() * 2
+            })
+            .build()
+            .unwrap();
+        assert_eq!(grid.to_2d_vec(), vec![vec![0, 2, 4], vec![6, 8, 10]]);
+        assert_eq!(visited, grid.coordinates().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn should_err_on_invalid_size() {
+        assert!(matches!(
+            GridBuilder::new(0, 3).filled(1).build(),
+            Err(GridError::InvalidSize)
+        ));
+        assert!(matches!(
+            GridBuilder::new(3, 0).filled_default().build() as Result<Grid<i32>, _>,
+            Err(GridError::InvalidSize)
+        ));
+        assert!(matches!(
+            GridBuilder::new(usize::MAX, 2).filled(1).build(),
+            Err(GridError::ExcessiveSize)
+        ));
+        assert!(matches!(
+            GridBuilder::<i32>::new(3, 3).build(),
+            Err(GridError::InvalidSize)
+        ));
+    }
+}
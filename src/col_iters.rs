@@ -9,20 +9,29 @@ impl<'a, T> Iterator for ColIter<'a, T> {
     fn next(&mut self) -> Option<Self::Item> {
         self.slice.next()
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.slice.size_hint()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for ColIter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.slice.next_back()
+    }
 }
 
+impl<'a, T> ExactSizeIterator for ColIter<'a, T> {}
+
 impl<'a, T> ColIter<'a, T> {
     pub(crate) fn new(grid: &'a Grid<T>, index: usize) -> ColIter<'a, T> {
         let col_start = crate::grid::col_start_index(grid, index);
         ColIter {
-            slice: grid
-                .items
-                .iter()
-                .skip(col_start)
-                .step_by(grid.cols),
+            slice: grid.items.iter().skip(col_start).step_by(grid.cols),
         }
     }
 
+    #[allow(clippy::iter_skip_zero)]
     pub(crate) fn noop() -> ColIter<'a, T> {
         ColIter {
             slice: [].iter().skip(0).step_by(1),
@@ -39,20 +48,29 @@ impl<'a, T> Iterator for MutColIter<'a, T> {
     fn next(&mut self) -> Option<Self::Item> {
         self.slice.next()
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.slice.size_hint()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for MutColIter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.slice.next_back()
+    }
 }
 
+impl<'a, T> ExactSizeIterator for MutColIter<'a, T> {}
+
 impl<'a, T> MutColIter<'a, T> {
     pub(crate) fn new(grid: &'a mut Grid<T>, index: usize) -> MutColIter<'a, T> {
         let col_start = crate::grid::col_start_index(grid, index);
         MutColIter {
-            slice: grid
-                .items
-                .iter_mut()
-                .skip(col_start)
-                .step_by(grid.cols),
+            slice: grid.items.iter_mut().skip(col_start).step_by(grid.cols),
         }
     }
 
+    #[allow(clippy::iter_skip_zero)]
     pub(crate) fn noop() -> MutColIter<'a, T> {
         MutColIter {
             slice: [].iter_mut().skip(0).step_by(1),
@@ -135,5 +153,49 @@ mod iter_tests {
             assert_eq!(iter.next(), Some(&mut 13));
             assert_eq!(iter.next(), None);
         }
+
+        #[test]
+        fn should_iter_over_col_in_reverse() {
+            let grid = center_grid();
+            let mut iter = grid.col_iter(5).rev();
+            assert_eq!(iter.next(), Some(&14));
+            assert_eq!(iter.next(), Some(&11));
+            assert_eq!(iter.next(), Some(&8));
+            assert_eq!(iter.next(), Some(&5));
+            assert_eq!(iter.next(), Some(&2));
+            assert_eq!(iter.next(), None);
+
+            let mut iter = ColIter::new(&grid, 1);
+            assert_eq!(iter.next_back(), Some(&13));
+            assert_eq!(iter.next(), Some(&1));
+            assert_eq!(iter.next_back(), Some(&10));
+            assert_eq!(iter.next(), Some(&4));
+            assert_eq!(iter.next_back(), Some(&7));
+            assert_eq!(iter.next(), None);
+            assert_eq!(iter.next_back(), None);
+        }
+
+        #[test]
+        fn should_mut_iter_over_col_in_reverse() {
+            let mut grid = center_grid();
+            let mut iter = MutColIter::new(&mut grid, 0);
+            assert_eq!(iter.next_back(), Some(&mut 12));
+            assert_eq!(iter.next(), Some(&mut 0));
+            assert_eq!(iter.next_back(), Some(&mut 9));
+            assert_eq!(iter.next(), Some(&mut 3));
+            assert_eq!(iter.next_back(), Some(&mut 6));
+            assert_eq!(iter.next(), None);
+
+            for (i, value) in MutColIter::new(&mut grid, 0).rev().enumerate() {
+                *value = i as i32;
+            }
+            let mut iter = ColIter::new(&grid, 0);
+            assert_eq!(iter.next(), Some(&4));
+            assert_eq!(iter.next(), Some(&3));
+            assert_eq!(iter.next(), Some(&2));
+            assert_eq!(iter.next(), Some(&1));
+            assert_eq!(iter.next(), Some(&0));
+            assert_eq!(iter.next(), None);
+        }
     }
 }
@@ -3,24 +3,71 @@ use std::fmt::{Debug, Display, Formatter};
 
 #[derive(Debug)]
 pub enum GridError {
-    IndexOutOfBounds,
-    RowSizeMismatch,
+    /// The coordinate that was outside of the grid, based on the `Origin` and other `GridOptions` of the grid
+    IndexOutOfBounds {
+        x: isize,
+        y: isize,
+    },
+    /// A row did not have the same number of cells as the other rows.  `row` is the zero based row number from the top, `expected` is the number of
+    /// columns, and `actual` is the length of the row.  `Grid::insert_column` also returns it for a column, with the column number as `row` and the
+    /// number of rows as `expected`
+    RowSizeMismatch {
+        row: usize,
+        expected: usize,
+        actual: usize,
+    },
+    /// The last row of a flat `Vec` did not fill a whole row.  `expected` is the number of columns, and `actual` is the number of cells left over
+    IncompleteRow {
+        expected: usize,
+        actual: usize,
+    },
+    /// The number of cells did not match the number of rows and columns.  `expected` is the product of the rows and columns, and `actual` is the number of cells
+    SizeMismatch {
+        expected: usize,
+        actual: usize,
+    },
     InvalidSize,
     ExcessiveSize,
     InvalidDivisionSize,
+    DuplicateIndex,
+    /// The operation needs at least one cell, and the grid has none
+    EmptyGrid,
+    /// The text that could not be parsed as an `Origin`
+    UnknownOrigin(String),
 }
 
 impl Display for GridError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
-            GridError::IndexOutOfBounds => write!(f, "Index out of bounds"),
-            GridError::RowSizeMismatch => write!(f, "Row size must match other rows"),
+            GridError::IndexOutOfBounds { x, y } => {
+                write!(f, "Index out of bounds at ({}, {})", x, y)
+            }
+            GridError::RowSizeMismatch {
+                row,
+                expected,
+                actual,
+            } => {
+                write!(
+                    f,
+                    "row {} has length {}, expected {}",
+                    row, actual, expected
+                )
+            }
+            GridError::IncompleteRow { expected, actual } => {
+                write!(f, "Last row has {} cells, expected {}", actual, expected)
+            }
+            GridError::SizeMismatch { expected, actual } => {
+                write!(f, "Expected {} cells, found {}", expected, actual)
+            }
             GridError::InvalidSize => write!(f, "Invalid grid size"),
             GridError::ExcessiveSize => write!(f, "Resulting grid is too large"),
             GridError::InvalidDivisionSize => write!(
                 f,
                 "Parameter passed if for divisor is either less than 1 or larger than the grid"
             ),
+            GridError::DuplicateIndex => write!(f, "Multiple indexes refer to the same cell"),
+            GridError::EmptyGrid => write!(f, "The grid has no cells"),
+            GridError::UnknownOrigin(s) => write!(f, "Unknown origin \"{}\"", s),
         }
     }
 }
@@ -1,12 +1,14 @@
 use crate::col_iters::{ColIter, MutColIter};
 use crate::error::GridError;
-use crate::index::Index;
-use crate::intogrid::IntoGrid;
+use crate::index::{out_of_bounds, GridIndex, Index};
+use crate::intogrid::{row_col_length_check, Columns, Dimensions, IntoGrid};
+use crate::line_iter::LineIter;
 pub use crate::origin::Origin;
 use crate::quaditers::NrantIterator;
+use crate::ring_iter::RingIter;
 use crate::row_iters::{MutRowIter, RowIter};
-use crate::xyneightbor::AllAroundNeighbor;
 pub use crate::xyneightbor::XyNeighbor;
+use crate::xyneightbor::{AllAroundNeighbor, Direction, LabeledNeighbor};
 
 const NEIGHBOR_Y_BASED: bool = true;
 const DEFAULT_WRAP: bool = false;
@@ -14,7 +16,7 @@ const DEFAULT_WRAP: bool = false;
 /// A collection that represents a 2-D grid with equal amount of cells in each row and equal number of cells in each column.  Supports different origin (location of 0,0) configurations,
 /// and includes methods to get neighbors of cells, iterators, and more.  Behind the scenes, the data is stored in a 1-D `Vec` to improve performance, but interaction with grid is done through normal (x,y)
 /// grid location methods.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Grid<T> {
     pub(crate) items: Vec<T>,
     pub(crate) rows: usize,
@@ -25,7 +27,7 @@ pub struct Grid<T> {
 /// Custom configuration of the grid.  For most grids out there, with x and y values always positive, an `origin: Origin::UpperLeft` and `inverted_y: true` is the best fit, and therefore is the default setting.
 /// `wrap_x` and `wrap_y` properties, if true wrap around the grid when calling `get_up` or `xy_neighbor` or any other method
 /// that returns neighbors of a called cell.  These parameters do not affect iterators.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct GridOptions {
     pub origin: Origin,
     pub inverted_y: bool,
@@ -45,6 +47,64 @@ impl Default for GridOptions {
         }
     }
 }
+
+impl GridOptions {
+    /// Sets the `origin`, for chaining off of `GridOptions::default()`
+    /// ```
+    /// use neighborgrid::*;
+    /// let gridoptions = GridOptions::default()
+    ///     .with_origin(Origin::Center)
+    ///     .with_inverted_y(false)
+    ///     .with_wrap(true, true);
+    /// assert_eq!(gridoptions.origin, Origin::Center);
+    /// assert!(gridoptions.wrap_x && gridoptions.wrap_y);
+    /// ```
+    pub fn with_origin(self, origin: Origin) -> Self {
+        GridOptions { origin, ..self }
+    }
+
+    /// Sets `inverted_y`, for chaining off of `GridOptions::default()`
+    pub fn with_inverted_y(self, inverted_y: bool) -> Self {
+        GridOptions { inverted_y, ..self }
+    }
+
+    /// Sets `neighbor_ybased`, for chaining off of `GridOptions::default()`
+    pub fn with_neighbor_ybased(self, neighbor_ybased: bool) -> Self {
+        GridOptions {
+            neighbor_ybased,
+            ..self
+        }
+    }
+
+    /// Returns true if `get_up` and the other "up" neighbor methods move to the cell with a y-coordinate one greater, and false if they move to the cell with
+    /// a y-coordinate one less.  "Down" is always the opposite.  The four combinations of `inverted_y` and `neighbor_ybased` work out to:
+    ///
+    /// | `inverted_y` | `neighbor_ybased` | up is       | up is visually            |
+    /// |--------------|-------------------|-------------|---------------------------|
+    /// | false        | false             | y + 1       | the row above             |
+    /// | false        | true              | y + 1       | the row above             |
+    /// | true         | false             | y - 1       | the row above             |
+    /// | true         | true              | y + 1       | the row below             |
+    ///
+    /// `neighbor_ybased` has no effect when `inverted_y` is false.
+    /// ```
+    /// use neighborgrid::*;
+    /// assert!(GridOptions::default().up_is_positive_y());
+    /// assert!(!GridOptions::default().with_neighbor_ybased(false).up_is_positive_y());
+    /// ```
+    pub fn up_is_positive_y(&self) -> bool {
+        !self.inverted_y || self.neighbor_ybased
+    }
+
+    /// Sets `wrap_x` and `wrap_y`, for chaining off of `GridOptions::default()`
+    pub fn with_wrap(self, wrap_x: bool, wrap_y: bool) -> Self {
+        GridOptions {
+            wrap_x,
+            wrap_y,
+            ..self
+        }
+    }
+}
 impl<T> Grid<T> {
     /// Create a new grid. If `options` is `None`, then default `GridOptions` are used.  Takes as parameter `items`, which is anything that implements the `IntoGrid` trait.  
     /// These are things like a 2-D Vec, 1-D vec with row parameters, and others.
@@ -57,17 +117,44 @@ impl<T> Grid<T> {
         Ok(grid)
     }
 
+    /// Creates a grid with no rows or columns, which can be grown with `insert_row` or `insert_column`.  The other constructors reject a size of zero.
+    /// Methods that look up a cell return `None` or `EmptyGrid`, and iterators yield nothing.
+    /// ```
+    /// use neighborgrid::*;
+    /// let mut grid = Grid::empty(None);
+    /// assert!(grid.is_empty());
+    /// grid.insert_row(0, vec![1, 2, 3]).expect("invalid row");
+    /// assert_eq!(grid.rows(), 1);
+    /// assert_eq!(grid.columns(), 3);
+    /// ```
+    pub fn empty(options: Option<GridOptions>) -> Self {
+        Grid::create(Vec::new(), 0, 0, options)
+    }
+
     /// Already have a 1-D Vec for your grid?  Use this method to create a `Grid`, just specify how many rows and columns.  
-    /// Returns Err if the vec size is not equal to the product of the rows and columns
+    /// Returns `SizeMismatch` if the vec size is not equal to the product of the rows and columns, `ExcessiveSize` if that product overflows, and
+    /// `InvalidSize` if either is zero.  Use `Grid::empty` for a grid with no cells.
+    /// ```
+    /// use neighborgrid::*;
+    /// let grid = Grid::new_from_1d(vec![0, 1, 2, 3, 4], 3, 2, None);
+    /// assert!(matches!(grid, Err(GridError::SizeMismatch { expected: 6, actual: 5 })));
+    /// ```
     pub fn new_from_1d(
         vec: Vec<T>,
         columns: usize,
         rows: usize,
         options: Option<GridOptions>,
     ) -> Result<Self, GridError> {
-        if vec.len() != rows.checked_mul(columns).ok_or(GridError::ExcessiveSize)? {
+        if columns == 0 || rows == 0 {
             return Err(GridError::InvalidSize);
         }
+        let expected = rows.checked_mul(columns).ok_or(GridError::ExcessiveSize)?;
+        if vec.len() != expected {
+            return Err(GridError::SizeMismatch {
+                expected,
+                actual: vec.len(),
+            });
+        }
         Ok(Grid {
             items: vec,
             rows,
@@ -76,12 +163,77 @@ impl<T> Grid<T> {
         })
     }
 
+    /// Same as `new_from_1d`, but takes the number of rows before the number of columns, matching the usual "rows x columns" order.
+    /// ```
+    /// use neighborgrid::*;
+    /// let grid = Grid::from_1d_rows_cols(vec![0, 1, 2, 3, 4, 5], 2, 3, None).expect("wrong number of cells");
+    /// assert_eq!(grid.rows(), 2);
+    /// assert_eq!(grid.columns(), 3);
+    /// ```
+    pub fn from_1d_rows_cols(
+        vec: Vec<T>,
+        rows: usize,
+        cols: usize,
+        options: Option<GridOptions>,
+    ) -> Result<Self, GridError> {
+        Grid::new_from_1d(vec, cols, rows, options)
+    }
+
+    /// Creates a grid from a 1-D Vec holding every cell in row major order, inferring the number of rows from the length of the vec.
+    /// Returns `IncompleteRow` if the length of the vec is not evenly divisible by `columns`, and `InvalidSize` if either is zero.
+    /// ```
+    /// use neighborgrid::*;
+    /// let grid = Grid::from_vec_with_cols(vec![0, 1, 2, 3, 4, 5], 3).expect("not a whole number of rows");
+    /// assert_eq!(grid.rows(), 2);
+    ///
+    /// let grid = Grid::from_vec_with_cols(vec![0, 1, 2, 3, 4, 5, 6], 3);
+    /// assert!(matches!(grid, Err(GridError::IncompleteRow { expected: 3, actual: 1 })));
+    /// ```
+    pub fn from_vec_with_cols(vec: Vec<T>, columns: usize) -> Result<Self, GridError> {
+        if columns != 0 && vec.len() % columns != 0 {
+            return Err(GridError::IncompleteRow {
+                expected: columns,
+                actual: vec.len() % columns,
+            });
+        }
+        Grid::new((vec, Columns(columns)), None)
+    }
+
+    /// Returns the same grid with new `GridOptions`, such as a different `Origin`, without copying the cells
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec![0, 1, 2],
+    ///             vec![3, 4, 5],
+    ///             vec![6, 7, 8],
+    /// ];
+    /// let grid = Grid::new(vec, None).expect("failed to import 2d vec");
+    /// assert_eq!(grid.get((0, 0)), Some(&0));
+    ///
+    /// let grid = grid.with_options(GridOptions::default().with_origin(Origin::Center));
+    /// assert_eq!(grid.get((0, 0)), Some(&4));
+    /// ```
+    pub fn with_options(self, options: GridOptions) -> Self {
+        Grid { options, ..self }
+    }
+
+    /// Replaces the `GridOptions`, such as to change the `Origin`, without copying the cells
+    pub fn set_options(&mut self, options: GridOptions) {
+        self.options = options;
+    }
+
     /// The number of cells in the grid
     #[inline]
     pub fn size(&self) -> usize {
         self.items.len()
     }
 
+    /// Returns true if the grid has no cells, such as one created with `Grid::empty`.  A grid with no cells has zero rows and zero columns.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
     /// The number of rows
     #[inline]
     pub fn rows(&self) -> usize {
@@ -94,8 +246,44 @@ impl<T> Grid<T> {
         self.cols
     }
 
+    /// The number of rows and columns together
+    /// ```
+    /// use neighborgrid::*;
+    /// let grid = Grid::new_from_1d((0..6).collect(), 3, 2, None).expect("failed to import 1d vec");
+    /// assert_eq!(grid.dimensions(), Dimensions { rows: 2, cols: 3 });
+    /// assert_eq!(grid.dimensions().total(), Some(6));
+    /// ```
+    #[inline]
+    pub fn dimensions(&self) -> Dimensions {
+        Dimensions {
+            rows: self.rows,
+            cols: self.cols,
+        }
+    }
+
+    /// Returns true if the grid has the same number of rows and columns
+    #[inline]
+    pub fn is_square(&self) -> bool {
+        self.rows == self.cols
+    }
+
+    /// The ratio of columns to rows, reduced to lowest terms.  An empty grid returns `(0, 0)`.
+    /// ```
+    /// use neighborgrid::*;
+    /// let grid = Grid::new_from_1d((0..24).collect(), 6, 4, None).expect("failed to import 1d vec");
+    /// assert_eq!(grid.aspect_ratio(), (3, 2));
+    /// assert!(!grid.is_square());
+    /// ```
+    pub fn aspect_ratio(&self) -> (usize, usize) {
+        let divisor = gcd(self.cols, self.rows);
+        if divisor == 0 {
+            return (0, 0);
+        }
+        (self.cols / divisor, self.rows / divisor)
+    }
+
     /// Returns a immutable reference to the value stored in the specified cell.  None if outside the grid bounds
-    pub fn get<I: Index>(&self, index: I) -> Option<&T> {
+    pub fn get<I: GridIndex>(&self, index: I) -> Option<&T> {
         if let Ok(index) = index.grid_index(self) {
             Some(&self.items[index])
         } else {
@@ -103,6 +291,162 @@ impl<T> Grid<T> {
         }
     }
 
+    /// Returns a reference to the value stored in the specified cell, or `default` if the cell is outside the grid bounds
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec![0, 1, 2],
+    ///             vec![3, 4, 5],
+    /// ];
+    /// let grid = Grid::new(vec, None).expect("failed to import 2d vec");
+    /// assert_eq!(grid.get_or((1, 1), &-1), &4);
+    /// assert_eq!(grid.get_or((3, 0), &-1), &-1);
+    /// ```
+    pub fn get_or<'a, I: GridIndex>(&'a self, index: I, default: &'a T) -> &'a T {
+        self.get(index).unwrap_or(default)
+    }
+
+    /// Returns a immutable reference to the value stored in the specified cell.  Unlike `get`, the `GridError` is returned if the cell is outside the grid bounds.
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec![0, 1, 2],
+    ///             vec![3, 4, 5],
+    /// ];
+    /// let grid = Grid::new(vec, None).expect("failed to import 2d vec");
+    /// assert_eq!(grid.get_result((1, 1)).expect("invalid coordinate"), &4);
+    /// assert!(matches!(grid.get_result((3, 0)), Err(GridError::IndexOutOfBounds { x: 3, y: 0 })));
+    /// ```
+    pub fn get_result<I: GridIndex>(&self, index: I) -> Result<&T, GridError> {
+        let index = index.grid_index(self)?;
+        Ok(&self.items[index])
+    }
+
+    /// Returns a mutable reference to the value stored in the specified cell.  Unlike `get_mut`, the `GridError` is returned if the cell is outside the grid bounds.
+    pub fn get_result_mut<I: GridIndex>(&mut self, index: I) -> Result<&mut T, GridError> {
+        let index = index.grid_index(self)?;
+        Ok(&mut self.items[index])
+    }
+
+    /// Replaces the value in the specified cell with the default value of `T`, and returns the old value.  Useful for emptying a grid of `Option`s.
+    /// ```
+    /// use neighborgrid::*;
+    /// let mut grid = Grid::new(vec![vec![Some(1), None], vec![Some(3), Some(4)]], None).expect("failed to import 2d vec");
+    /// assert_eq!(grid.take((0, 1)).expect("invalid coordinate"), Some(3));
+    /// assert_eq!(grid.get((0, 1)), Some(&None));
+    /// ```
+    pub fn take<I: GridIndex>(&mut self, index: I) -> Result<T, GridError>
+    where
+        T: Default,
+    {
+        Ok(std::mem::take(self.get_result_mut(index)?))
+    }
+
+    /// Sets the specified cell to the default value of `T`
+    pub fn set_default<I: GridIndex>(&mut self, index: I) -> Result<(), GridError>
+    where
+        T: Default,
+    {
+        *self.get_result_mut(index)? = T::default();
+        Ok(())
+    }
+
+    /// Returns the value stored in the specified cell, wrapping coordinates outside the grid back into it on both axes, no matter how far outside
+    /// they are.  This ignores the `wrap_x` and `wrap_y` `GridOptions`, and always treats the grid as a torus.
+    ///
+    /// Panics if the grid is empty, or if `index` is outside the grid and is a custom `GridIndex` that does not override `wrapped_position`.
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec![0, 1, 2],
+    ///             vec![3, 4, 5],
+    /// ];
+    /// let grid = Grid::new(vec, None).expect("failed to import 2d vec");
+    /// assert_eq!(grid.wrapping_get((1, 1)), &4);
+    /// assert_eq!(grid.wrapping_get((4, 3)), &4);
+    /// assert_eq!(grid.wrapping_get((-1, -1)), &5);
+    /// ```
+    pub fn wrapping_get<I: GridIndex>(&self, index: I) -> &T {
+        let (col, row) = index
+            .wrapped_position(self)
+            .expect("index does not support positions outside of the grid");
+        &self.items[row * self.cols + col]
+    }
+
+    /// Returns the coordinate `dx` and `dy` away from the specified cell, or `None` if that is off the edge of the grid or `index` is not in the grid.
+    /// The offset is in the same coordinates as `index`, and wraps around the grid when the `GridOptions` allow it.
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec![0, 1, 2],
+    ///             vec![3, 4, 5],
+    /// ];
+    /// let grid = Grid::new(vec, None).expect("failed to import 2d vec");
+    /// assert_eq!(grid.offset((0, 0), 2, 1), Some((2, 1)));
+    /// assert_eq!(grid.offset((0, 0), -1, 0), None);
+    ///
+    /// let grid = grid.with_options(GridOptions::default().with_wrap(true, false));
+    /// assert_eq!(grid.offset((0, 0), -1, 0), Some((2, 0)));
+    /// ```
+    pub fn offset<I: GridIndex>(&self, index: I, dx: isize, dy: isize) -> Option<(isize, isize)> {
+        let index = index.grid_index(self).ok()?;
+        let (cols, rows) = (self.cols as isize, self.rows as isize);
+        // Reduce wrapping offsets first, so that a large offset cannot overflow
+        let dx = if self.options.wrap_x {
+            dx.rem_euclid(cols)
+        } else {
+            dx
+        };
+        let dy = if self.options.wrap_y {
+            dy.rem_euclid(rows)
+        } else {
+            dy
+        };
+        // Internal rows count down from the top, which is the direction of y only when it is inverted
+        let drow = if self.is_inverted_y() {
+            dy
+        } else {
+            dy.checked_neg()?
+        };
+        let mut col = ((index % self.cols) as isize).checked_add(dx)?;
+        let mut row = ((index / self.cols) as isize).checked_add(drow)?;
+        if self.options.wrap_x {
+            col = col.rem_euclid(cols);
+        }
+        if self.options.wrap_y {
+            row = row.rem_euclid(rows);
+        }
+        if col < 0 || row < 0 || col >= self.cols as isize || row >= self.rows as isize {
+            return None;
+        }
+        Some(Index::output(row as usize * self.cols + col as usize, self))
+    }
+
+    /// The index of the specified cell in the row major `Vec` the grid is stored in, for use with other data laid out the same way
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec![0, 1, 2],
+    ///             vec![3, 4, 5],
+    /// ];
+    /// let grid = Grid::new(vec, None).expect("failed to import 2d vec");
+    /// assert_eq!(grid.flat_index((1, 1)).expect("invalid coordinate"), 4);
+    /// assert_eq!(grid.coord_of(4), Some((1, 1)));
+    /// ```
+    pub fn flat_index<I: GridIndex>(&self, index: I) -> Result<usize, GridError> {
+        index.grid_index(self)
+    }
+
+    /// The coordinate of the cell at `flat` in the row major `Vec` the grid is stored in, or `None` if there is no such cell.  The reverse of `flat_index`.
+    pub fn coord_of(&self, flat: usize) -> Option<(isize, isize)> {
+        (flat < self.size()).then(|| Index::output(flat, self))
+    }
+
+    /// Returns true if the specified cell is inside the grid bounds, without fetching the value
+    pub fn contains<I: GridIndex>(&self, index: I) -> bool {
+        index.grid_index(self).is_ok()
+    }
+
     /// Returns a mutable reference to the value stored in the specified cell.  None if outside the grid bounds
     /// ```
     /// use neighborgrid::*;
@@ -125,7 +469,7 @@ impl<T> Grid<T> {
     /// *middle_cell = 8;
     /// assert_eq!(middle_cell, &mut 8);
     /// ```
-    pub fn get_mut<I: Index>(&mut self, index: I) -> Option<&mut T> {
+    pub fn get_mut<I: GridIndex>(&mut self, index: I) -> Option<&mut T> {
         if let Ok(index) = index.grid_index(self) {
             Some(&mut self.items[index])
         } else {
@@ -133,6 +477,78 @@ impl<T> Grid<T> {
         }
     }
 
+    /// Returns mutable references to two different cells at the same time.  Returns `IndexOutOfBounds` if either cell is outside the grid bounds,
+    /// and `DuplicateIndex` if both refer to the same cell.
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec![0, 1, 2],
+    ///             vec![3, 4, 5],
+    /// ];
+    /// let mut grid = Grid::new(vec, None).expect("failed to import 2d vec");
+    ///
+    /// let (a, b) = grid.get_pair_mut((0, 0), (2, 1)).expect("invalid coordinates");
+    /// *a += *b;
+    /// *b = 0;
+    /// assert_eq!(grid.get((0, 0)), Some(&5));
+    /// assert_eq!(grid.get((2, 1)), Some(&0));
+    /// ```
+    pub fn get_pair_mut<I: GridIndex>(
+        &mut self,
+        a: I,
+        b: I,
+    ) -> Result<(&mut T, &mut T), GridError> {
+        let [a, b] = self.get_disjoint_mut([a, b])?;
+        Ok((a, b))
+    }
+
+    /// Returns mutable references to any number of different cells at the same time, in the same order as `indexes`.  Returns `IndexOutOfBounds` if
+    /// any cell is outside the grid bounds, and `DuplicateIndex` if any two refer to the same cell.
+    pub fn get_disjoint_mut<I: GridIndex, const N: usize>(
+        &mut self,
+        indexes: [I; N],
+    ) -> Result<[&mut T; N], GridError> {
+        let mut resolved = [None; N];
+        for (slot, index) in resolved.iter_mut().zip(indexes) {
+            *slot = Some(index.grid_index(self)?);
+        }
+        let cells = self.disjoint_cells_mut(resolved)?;
+        Ok(cells.map(|cell| cell.expect("Every index was resolved above")))
+    }
+
+    /// Returns mutable references to a cell and its four cardinal neighbors at the same time, as `[center, up, down, left, right]`, such as for
+    /// diffusing a value into the neighbors.  Neighbors off the edge of a non-wrapping grid are `None`.  Returns `IndexOutOfBounds` if the cell is outside
+    /// the grid bounds, and `DuplicateIndex` if the grid is so small that wrapping makes two of the cells the same cell.
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec![0, 0, 0],
+    ///             vec![0, 8, 0],
+    ///             vec![0, 0, 0],
+    /// ];
+    /// let mut grid = Grid::new(vec, None).expect("failed to import 2d vec");
+    /// let [center, neighbors @ ..] = grid.cardinal_block_mut((1, 1)).expect("invalid coordinate");
+    /// let center = center.expect("center is always present");
+    /// for neighbor in neighbors.into_iter().flatten() {
+    ///     *neighbor += 2;
+    ///     *center -= 2;
+    /// }
+    /// assert_eq!(grid.to_2d_vec(), vec![vec![0, 2, 0], vec![2, 0, 2], vec![0, 2, 0]]);
+    /// ```
+    pub fn cardinal_block_mut<I: GridIndex>(
+        &mut self,
+        index: I,
+    ) -> Result<[Option<&mut T>; 5], GridError> {
+        let center = index.grid_index(self)?;
+        self.disjoint_cells_mut([
+            Some(center),
+            self.up_idx(center).ok(),
+            self.down_idx(center).ok(),
+            self.left_idx(center).ok(),
+            self.right_idx(center).ok(),
+        ])
+    }
+
     /// Return an immutable reference to the value stored in the cell with a 1 higher y-value. None if outside grid bounds
     /// ```
     /// use neighborgrid::*;
@@ -220,141 +636,214 @@ impl<T> Grid<T> {
     /// assert_eq!(grid.get_up((2, -4)), None);
     /// ```
     #[inline]
-    pub fn get_up<I: Index>(&self, index: I) -> Option<&T> {
+    pub fn get_up<I: GridIndex>(&self, index: I) -> Option<&T> {
         let idx = self.up_idx(index).ok()?;
         Some(&self.items[idx])
     }
 
     #[inline]
-    pub fn get_down<I: Index>(&self, index: I) -> Option<&T> {
+    pub fn get_down<I: GridIndex>(&self, index: I) -> Option<&T> {
         let idx = self.down_idx(index).ok()?;
         Some(&self.items[idx])
     }
 
     #[inline]
-    pub fn get_left<I: Index>(&self, index: I) -> Option<&T> {
+    pub fn get_left<I: GridIndex>(&self, index: I) -> Option<&T> {
         let idx = self.left_idx(index).ok()?;
         Some(&self.items[idx])
     }
 
     #[inline]
-    pub fn get_right<I: Index>(&self, index: I) -> Option<&T> {
+    pub fn get_right<I: GridIndex>(&self, index: I) -> Option<&T> {
         let idx = self.right_idx(index).ok()?;
         Some(&self.items[idx])
     }
 
     #[inline]
-    pub fn get_upleft<I: Index>(&self, index: I) -> Option<&T> {
+    pub fn get_upleft<I: GridIndex>(&self, index: I) -> Option<&T> {
         let idx = self.upleft_idx(index).ok()?;
         Some(&self.items[idx])
     }
 
     #[inline]
-    pub fn get_upright<I: Index>(&self, index: I) -> Option<&T> {
+    pub fn get_upright<I: GridIndex>(&self, index: I) -> Option<&T> {
         let idx = self.upright_idx(index).ok()?;
         Some(&self.items[idx])
     }
 
     #[inline]
-    pub fn get_downleft<I: Index>(&self, index: I) -> Option<&T> {
+    pub fn get_downleft<I: GridIndex>(&self, index: I) -> Option<&T> {
         let idx = self.downleft_idx(index).ok()?;
         Some(&self.items[idx])
     }
 
     #[inline]
-    pub fn get_downright<I: Index>(&self, index: I) -> Option<&T> {
+    pub fn get_downright<I: GridIndex>(&self, index: I) -> Option<&T> {
         let idx = self.downright_idx(index).ok()?;
         Some(&self.items[idx])
     }
 
     #[inline]
-    pub fn get_up_mut<I: Index>(&mut self, index: I) -> Option<&mut T> {
+    pub fn get_up_mut<I: GridIndex>(&mut self, index: I) -> Option<&mut T> {
         let idx = self.up_idx(index).ok()?;
         Some(&mut self.items[idx])
     }
 
     #[inline]
-    pub fn get_down_mut<I: Index>(&mut self, index: I) -> Option<&mut T> {
+    pub fn get_down_mut<I: GridIndex>(&mut self, index: I) -> Option<&mut T> {
         let idx = self.down_idx(index).ok()?;
         Some(&mut self.items[idx])
     }
 
     #[inline]
-    pub fn get_left_mut<I: Index>(&mut self, index: I) -> Option<&mut T> {
+    pub fn get_left_mut<I: GridIndex>(&mut self, index: I) -> Option<&mut T> {
         let idx = self.left_idx(index).ok()?;
         Some(&mut self.items[idx])
     }
 
     #[inline]
-    pub fn get_right_mut<I: Index>(&mut self, index: I) -> Option<&mut T> {
+    pub fn get_right_mut<I: GridIndex>(&mut self, index: I) -> Option<&mut T> {
         let idx = self.right_idx(index).ok()?;
         Some(&mut self.items[idx])
     }
 
     #[inline]
-    pub fn get_upleft_mut<I: Index>(&mut self, index: I) -> Option<&mut T> {
+    pub fn get_upleft_mut<I: GridIndex>(&mut self, index: I) -> Option<&mut T> {
         let idx = self.upleft_idx(index).ok()?;
         Some(&mut self.items[idx])
     }
 
     #[inline]
-    pub fn get_upright_mut<I: Index>(&mut self, index: I) -> Option<&mut T> {
+    pub fn get_upright_mut<I: GridIndex>(&mut self, index: I) -> Option<&mut T> {
         let idx = self.upright_idx(index).ok()?;
         Some(&mut self.items[idx])
     }
 
     #[inline]
-    pub fn get_downleft_mut<I: Index>(&mut self, index: I) -> Option<&mut T> {
+    pub fn get_downleft_mut<I: GridIndex>(&mut self, index: I) -> Option<&mut T> {
         let idx = self.downleft_idx(index).ok()?;
         Some(&mut self.items[idx])
     }
 
     #[inline]
-    pub fn get_downright_mut<I: Index>(&mut self, index: I) -> Option<&mut T> {
+    pub fn get_downright_mut<I: GridIndex>(&mut self, index: I) -> Option<&mut T> {
         let idx = self.downright_idx(index).ok()?;
         Some(&mut self.items[idx])
     }
 
+    /// Returns the neighbor above the cell, or the cell itself if there is no neighbor, clamping to the edge of the grid.  This is useful for image
+    /// filters, where the cells along the border are repeated rather than special cased.  The other `_clamped` methods work the same way in their direction.
+    ///
+    /// Panics if `index` is outside the grid bounds.
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec![0, 1, 2],
+    ///             vec![3, 4, 5],
+    /// ];
+    /// let gridoptions = GridOptions {
+    ///        neighbor_ybased: false,
+    ///        ..GridOptions::default()
+    /// };
+    /// let grid = Grid::new(vec, Some(gridoptions)).expect("failed to import 2d vec");
+    /// assert_eq!(grid.get_up_clamped((1, 1)), &1);
+    /// assert_eq!(grid.get_up_clamped((1, 0)), &1);
+    /// ```
+    #[inline]
+    pub fn get_up_clamped<I: GridIndex>(&self, index: I) -> &T {
+        self.clamped(index, Self::up_idx)
+    }
+
     #[inline]
-    fn down_idx<I: Index>(&self, index: I) -> Result<usize, GridError> {
+    pub fn get_down_clamped<I: GridIndex>(&self, index: I) -> &T {
+        self.clamped(index, Self::down_idx)
+    }
+
+    #[inline]
+    pub fn get_left_clamped<I: GridIndex>(&self, index: I) -> &T {
+        self.clamped(index, Self::left_idx)
+    }
+
+    #[inline]
+    pub fn get_right_clamped<I: GridIndex>(&self, index: I) -> &T {
+        self.clamped(index, Self::right_idx)
+    }
+
+    #[inline]
+    pub fn get_upleft_clamped<I: GridIndex>(&self, index: I) -> &T {
+        self.clamped(index, Self::upleft_idx)
+    }
+
+    #[inline]
+    pub fn get_upright_clamped<I: GridIndex>(&self, index: I) -> &T {
+        self.clamped(index, Self::upright_idx)
+    }
+
+    #[inline]
+    pub fn get_downleft_clamped<I: GridIndex>(&self, index: I) -> &T {
+        self.clamped(index, Self::downleft_idx)
+    }
+
+    #[inline]
+    pub fn get_downright_clamped<I: GridIndex>(&self, index: I) -> &T {
+        self.clamped(index, Self::downright_idx)
+    }
+
+    fn clamped<I: GridIndex>(
+        &self,
+        index: I,
+        neighbor: fn(&Self, usize) -> Result<usize, GridError>,
+    ) -> &T {
+        let index = index
+            .grid_index(self)
+            .expect("index must be inside the grid bounds");
+        &self.items[neighbor(self, index).unwrap_or(index)]
+    }
+
+    #[inline]
+    pub(crate) fn down_idx<I: GridIndex>(&self, index: I) -> Result<usize, GridError> {
+        self.down_idx_wrap(index, self.options.wrap_y)
+    }
+
+    fn down_idx_wrap<I: GridIndex>(&self, index: I, wrap_y: bool) -> Result<usize, GridError> {
         let index = index.grid_index(self)?;
         if self.is_inverted_y() && self.neighbor_ybased_invert() {
-            self.actual_up_ind(index)
+            self.actual_up_ind(index, wrap_y)
         } else {
-            self.actual_down_ind(index)
+            self.actual_down_ind(index, wrap_y)
         }
     }
 
     #[inline]
-    fn actual_down_ind(&self, index: usize) -> Result<usize, GridError> {
+    fn actual_down_ind(&self, index: usize, wrap_y: bool) -> Result<usize, GridError> {
         let res = index + self.cols;
         if res < self.size() {
             Ok(res)
-        } else if self.options.wrap_y {
+        } else if wrap_y {
             Ok(res - self.size())
         } else {
-            Err(GridError::IndexOutOfBounds)
+            Err(self.neighbor_out_of_bounds(index, 0, 1))
         }
     }
 
     #[inline]
-    fn downleft_idx<I: Index>(&self, index: I) -> Result<usize, GridError> {
+    pub(crate) fn downleft_idx<I: GridIndex>(&self, index: I) -> Result<usize, GridError> {
         self.down_idx(index).and_then(|i| self.left_idx(i))
     }
 
     #[inline]
-    fn downright_idx<I: Index>(&self, index: I) -> Result<usize, GridError> {
+    pub(crate) fn downright_idx<I: GridIndex>(&self, index: I) -> Result<usize, GridError> {
         self.down_idx(index).and_then(|i| self.right_idx(i))
     }
 
-    fn actual_up_ind(&self, index: usize) -> Result<usize, GridError> {
+    fn actual_up_ind(&self, index: usize, wrap_y: bool) -> Result<usize, GridError> {
         match index.checked_sub(self.cols) {
             Some(v) => Ok(v),
             None => {
-                if self.options.wrap_y {
+                if wrap_y {
                     Ok(index + self.size() - self.cols)
                 } else {
-                    Err(GridError::IndexOutOfBounds)
+                    Err(self.neighbor_out_of_bounds(index, 0, -1))
                 }
             }
         }
@@ -365,51 +854,120 @@ impl<T> Grid<T> {
         self.options.neighbor_ybased
     }
 
-    fn up_idx<I: Index>(&self, index: I) -> Result<usize, GridError> {
+    pub(crate) fn up_idx<I: GridIndex>(&self, index: I) -> Result<usize, GridError> {
+        self.up_idx_wrap(index, self.options.wrap_y)
+    }
+
+    fn up_idx_wrap<I: GridIndex>(&self, index: I, wrap_y: bool) -> Result<usize, GridError> {
         let index = index.grid_index(self)?;
         if self.is_inverted_y() && self.neighbor_ybased_invert() {
-            self.actual_down_ind(index)
+            self.actual_down_ind(index, wrap_y)
         } else {
-            self.actual_up_ind(index)
+            self.actual_up_ind(index, wrap_y)
         }
     }
 
     #[inline]
-    fn upleft_idx<I: Index>(&self, index: I) -> Result<usize, GridError> {
+    pub(crate) fn upleft_idx<I: GridIndex>(&self, index: I) -> Result<usize, GridError> {
         self.up_idx(index).and_then(|i| self.left_idx(i))
     }
 
     #[inline]
-    fn upright_idx<I: Index>(&self, index: I) -> Result<usize, GridError> {
+    pub(crate) fn upright_idx<I: GridIndex>(&self, index: I) -> Result<usize, GridError> {
         self.up_idx(index).and_then(|i| self.right_idx(i))
     }
 
-    fn left_idx<I: Index>(&self, index: I) -> Result<usize, GridError> {
+    pub(crate) fn left_idx<I: GridIndex>(&self, index: I) -> Result<usize, GridError> {
+        self.left_idx_wrap(index, self.options.wrap_x)
+    }
+
+    fn left_idx_wrap<I: GridIndex>(&self, index: I, wrap_x: bool) -> Result<usize, GridError> {
         let index = index.grid_index(self)?;
         if index == 0 || index % self.cols == 0 {
-            if self.options.wrap_x {
+            if wrap_x {
                 Ok(index + self.columns() - 1)
             } else {
-                Err(GridError::IndexOutOfBounds)
+                Err(self.neighbor_out_of_bounds(index, -1, 0))
             }
         } else {
             Ok(index - 1)
         }
     }
 
-    fn right_idx<I: Index>(&self, index: I) -> Result<usize, GridError> {
+    pub(crate) fn right_idx<I: GridIndex>(&self, index: I) -> Result<usize, GridError> {
+        self.right_idx_wrap(index, self.options.wrap_x)
+    }
+
+    fn right_idx_wrap<I: GridIndex>(&self, index: I, wrap_x: bool) -> Result<usize, GridError> {
         let index = index.grid_index(self)? + 1;
         if index == self.size() || index % self.cols == 0 {
-            if self.options.wrap_x {
+            if wrap_x {
                 Ok(index - self.columns())
             } else {
-                Err(GridError::IndexOutOfBounds)
+                Err(self.neighbor_out_of_bounds(index - 1, 1, 0))
             }
         } else {
             Ok(index)
         }
     }
 
+    /// Error for a neighbor that is off the edge of the grid, `col_offset` and `row_offset` are relative to the internal vec index
+    fn neighbor_out_of_bounds(
+        &self,
+        index: usize,
+        col_offset: isize,
+        row_offset: isize,
+    ) -> GridError {
+        out_of_bounds(
+            self,
+            (index % self.cols) as isize + col_offset,
+            (index / self.cols) as isize + row_offset,
+        )
+    }
+
+    /// The internal vec indexes of the neighbors that are in the grid, or wrapped into it.  The diagonal neighbors are included when `diagonal` is true.
+    pub(crate) fn neighbor_indexes(
+        &self,
+        index: usize,
+        diagonal: bool,
+    ) -> impl Iterator<Item = usize> + Clone + '_ {
+        self.labeled_neighbor_indexes(index, diagonal)
+            .map(|(_, i)| i)
+    }
+
+    /// Same as `neighbor_indexes`, with the direction each neighbor is in
+    pub(crate) fn labeled_neighbor_indexes(
+        &self,
+        index: usize,
+        diagonal: bool,
+    ) -> impl Iterator<Item = (Direction, usize)> + Clone + '_ {
+        let diagonals: &[Direction] = if diagonal { &Direction::DIAGONAL } else { &[] };
+        Direction::CARDINAL
+            .iter()
+            .chain(diagonals)
+            .filter_map(move |&direction| {
+                Some((direction, self.direction_idx(index, direction).ok()?))
+            })
+    }
+
+    /// The internal vec index of the neighbor in `direction`
+    pub(crate) fn direction_idx(
+        &self,
+        index: usize,
+        direction: Direction,
+    ) -> Result<usize, GridError> {
+        match direction {
+            Direction::Up => self.up_idx(index),
+            Direction::Down => self.down_idx(index),
+            Direction::Left => self.left_idx(index),
+            Direction::Right => self.right_idx(index),
+            Direction::UpLeft => self.upleft_idx(index),
+            Direction::UpRight => self.upright_idx(index),
+            Direction::DownLeft => self.downleft_idx(index),
+            Direction::DownRight => self.downright_idx(index),
+        }
+    }
+
     #[inline]
     fn _get(&self, index: usize) -> Option<&T> {
         self.items.get(index)
@@ -463,7 +1021,7 @@ impl<T> Grid<T> {
 
     /// Returns which Nth-rant (or whatever the actual mathy term is) the index is in. Quadrant size is done with ceiling math, so grids not evenly divisible by the `divisor` will have smaller amount of cells in the bottom and right quadrants.
     /// For example, if you have a 9X9 grid and want sections 3x3, like a Sudoku puzzle, you would use a divisor of 3 ( 9 / 3 == 3 );
-    pub fn nrant<I: Index>(&self, index: I, divisor: usize) -> Result<usize, GridError> {
+    pub fn nrant<I: GridIndex>(&self, index: I, divisor: usize) -> Result<usize, GridError> {
         if divisor < 1 || divisor > std::cmp::max(self.rows(), self.columns()) {
             return Err(GridError::InvalidDivisionSize);
         }
@@ -482,12 +1040,12 @@ impl<T> Grid<T> {
         let x_rants = nrant % divisor;
         let y_rants = nrant / divisor;
         let x_offset = x_rants * ceiling(self.columns(), divisor);
-        let y_offset = self.rows() / divisor * y_rants;
+        let y_offset = y_rants * ceiling(self.rows(), divisor);
         y_offset * self.columns() + x_offset
     }
 
     /// Returns which quadrant the index is in.  GridOptions configuration does not have an impact. This is a simplified call to `self.nrant(index, 2)`
-    pub fn quadrant<I: Index>(&self, index: I) -> Result<usize, GridError> {
+    pub fn quadrant<I: GridIndex>(&self, index: I) -> Result<usize, GridError> {
         self.nrant(index, 2)
     }
 
@@ -514,7 +1072,7 @@ impl<T> Grid<T> {
     /// assert_eq!(iter.next(), Some(&5));
     /// assert_eq!(iter.next(), None)
     ///```
-    pub fn row_iter<'b, 'a: 'b, I: Index>(&'a self, index: I) -> RowIter<'b, T> {
+    pub fn row_iter<'b, 'a: 'b, I: GridIndex>(&'a self, index: I) -> RowIter<'b, T> {
         let res = index.grid_index(self);
         // Noop coverts invalid grid location Result into an iterator that returns None right way
         match res {
@@ -548,7 +1106,7 @@ impl<T> Grid<T> {
     /// assert_eq!(iter.next(), Some(&14));
     /// assert_eq!(iter.next(), None)
     ///```
-    pub fn col_iter<'b, 'a: 'b, I: Index>(&'a self, index: I) -> ColIter<'b, T> {
+    pub fn col_iter<'b, 'a: 'b, I: GridIndex>(&'a self, index: I) -> ColIter<'b, T> {
         let res = index.grid_index(self);
         // Noop coverts invalid grid location Result into an iterator that returns None right way
         match res {
@@ -557,273 +1115,2244 @@ impl<T> Grid<T> {
         }
     }
 
-    /// Swap two cells with each other.
-    pub fn swap<I: Index>(&mut self, a: I, b: I) -> Result<(), GridError> {
-        let a = a.grid_index(self)?;
-        let b = b.grid_index(self)?;
-        self.items.swap(a, b);
-        Ok(())
-    }
-
-    pub fn row_iter_mut<'b, 'a: 'b, I: Index>(&'a mut self, index: I) -> MutRowIter<'b, T> {
-        let res = index.grid_index(self);
-        // Noop coverts invalid grid location Result into an iterator that returns None right way
-        match res {
-            Ok(i) => MutRowIter::new(self, i),
-            Err(_) => MutRowIter::noop(),
-        }
-    }
-
-    pub fn col_iter_mut<'b, 'a: 'b, I: Index>(&'a mut self, index: I) -> MutColIter<'b, T> {
-        let res = index.grid_index(self);
-        // Noop coverts invalid grid location Result into an iterator that returns None right way
-        match res {
-            Ok(i) => MutColIter::new(self, i),
-            Err(_) => MutColIter::noop(),
-        }
-    }
-
-    /// Returns an `nrant_iter` with a divisor of 2.  Hence, the grid is split into 4 quadrants and iterates over the quadrant that the
-    /// index belongs to, from the start of the quadrant to the end of the quadrant.
-    pub fn quadrant_iter<'b, 'a: 'b, I: Index>(&'a self, index: I) -> NrantIterator<'b, T> {
-        self.nrant_iter(2, index)
-    }
-
-    /// Divides the grid, as specified by `divisor`, and produces `divisor * divisor` amount of sections.  For example, if `divisor` is `2`, then
-    /// the grid is divided into quadrants.  Making a Sudoku puzzle and want 9 sections (each 3x3) for your 9x9 grid?  Use a `divisor` of `3`.
-    /// ```
-    /// use neighborgrid::*;
-    /// let mut vec = vec![];
-    ///
-    ///for i in 1..=81 {
-    ///    vec.push(i);
-    ///}
-    ///
-    ///let grid = Grid::new_from_1d(vec, 9, 9, None).unwrap();
-    ///
-    ///let mut iter = grid.nrant_iter(3, (1,1));
-    ///assert_eq!(iter.next(), Some(Some(&1)));
-    ///assert_eq!(iter.next(), Some(Some(&2)));
-    ///assert_eq!(iter.next(), Some(Some(&3)));
-    ///assert_eq!(iter.next(), Some(Some(&10)));
-    ///assert_eq!(iter.next(), Some(Some(&11)));
-    ///assert_eq!(iter.next(), Some(Some(&12)));
-    ///assert_eq!(iter.next(), Some(Some(&19)));
-    ///assert_eq!(iter.next(), Some(Some(&20)));
-    ///assert_eq!(iter.next(), Some(Some(&21)));
-    ///assert_eq!(iter.next(), None);
-    ///```
-
-    pub fn nrant_iter<'b, 'a: 'b, I: Index>(
-        &'a self,
-        divisor: usize,
-        index: I,
-    ) -> NrantIterator<'b, T> {
-        let res = index.grid_index(self);
-        // Noop coverts invalid grid location Result into an iterator that returns None right way
-        match res {
-            Ok(i) => NrantIterator::new(self, divisor, i),
-            Err(_) => NrantIterator::noop(self),
-        }
-    }
-    /// Returns an `XyNeighbor` which are the four neighbors in cardinal directions from the called cell location
+    /// Returns an iterator over the row with the y-coordinate `y`, from left to right.  Only the row matters, so no x-coordinate is needed.  The iterator is
+    /// empty if `y` is outside the grid bounds.
     /// ```
     /// use neighborgrid::*;
     /// let vec = vec![
     ///             vec![0, 1, 2],
     ///             vec![3, 4, 5],
     ///             vec![6, 7, 8],
-    ///             vec![9, 10, 11],
-    ///             vec![12, 13, 14],
     /// ];
     /// let gridoptions = GridOptions {
     ///        origin: Origin::Center,
     ///        inverted_y: false,
     ///        ..GridOptions::default()
     /// };
-    /// let mut grid = Grid::new(vec, Some(gridoptions)).expect("failed to import 2d vec");
+    /// let grid = Grid::new(vec, Some(gridoptions)).expect("failed to import 2d vec");
     ///
-    /// let neighbors = grid.xy_neighbors((-1,-2)).expect("was not a valid coodinate"); // Neighbors of the item with 12 in it.
-    /// assert_eq!(neighbors.up, Some(&9));
-    /// assert_eq!(neighbors.down, None);
-    /// assert_eq!(neighbors.left, None);
-    /// assert_eq!(neighbors.right, Some(&13));
+    /// let row: Vec<_> = grid.iter_row(-1).collect();
+    /// assert_eq!(row, vec![&6, &7, &8]);
+    /// assert_eq!(grid.iter_row(2).next(), None);
     ///```
-    /// If `GridOptions` `x_wrap` and / or `y_wrap` are true, then the wrapped neighbors will be returned
+    pub fn iter_row(&self, y: isize) -> RowIter<'_, T> {
+        // An x of 0 is on the grid for every `Origin`, and the row iterator always starts from the left of the grid
+        self.row_iter((0, y))
+    }
+
+    /// Returns an iterator over the column with the x-coordinate `x`, from top to bottom.  Only the column matters, so no y-coordinate is needed.  The
+    /// iterator is empty if `x` is outside the grid bounds.
     /// ```
     /// use neighborgrid::*;
     /// let vec = vec![
     ///             vec![0, 1, 2],
     ///             vec![3, 4, 5],
     ///             vec![6, 7, 8],
-    ///             vec![9, 10, 11],
-    ///             vec![12, 13, 14],
     /// ];
     /// let gridoptions = GridOptions {
     ///        origin: Origin::Center,
     ///        inverted_y: false,
-    ///        wrap_x: true, // turn on wrapping
-    ///        wrap_y: true,
     ///        ..GridOptions::default()
     /// };
-    /// let mut grid = Grid::new(vec, Some(gridoptions)).expect("failed to import 2d vec");
+    /// let grid = Grid::new(vec, Some(gridoptions)).expect("failed to import 2d vec");
     ///
-    /// let neighbors = grid.xy_neighbors((-1,-2)).expect("was not a valid coodinate"); // Neighbors of the item with 12 in it.
-    /// assert_eq!(neighbors.up, Some(&9));
-    /// assert_eq!(neighbors.down, Some(&0));
-    /// assert_eq!(neighbors.left, Some(&14));
-    /// assert_eq!(neighbors.right, Some(&13));
+    /// let col: Vec<_> = grid.iter_col(-1).collect();
+    /// assert_eq!(col, vec![&0, &3, &6]);
+    /// assert_eq!(grid.iter_col(2).next(), None);
     ///```
-    pub fn xy_neighbors<I: Index>(&self, index: I) -> Result<XyNeighbor<'_, T>, GridError> {
-        let index = index.grid_index(self)?;
-        Ok(XyNeighbor {
-            up: self.get_up(index),
-            down: self.get_down(index),
-            left: self.get_left(index),
-            right: self.get_right(index),
-        })
+    pub fn iter_col(&self, x: isize) -> ColIter<'_, T> {
+        // A y of 0 is on the grid for every `Origin`, and the column iterator always starts from the top of the grid
+        self.col_iter((x, 0))
     }
 
-    /// Returns an `AllAroundNeighbor` of the neighbors of the specified cell. Order is left, right, bottom, top of index called.
+    /// Returns the cells of the internal row number `row`, zero based from the top of the grid regardless of `Origin`, from left to right.
+    /// `None` if `row` is not a row in the grid.
     /// ```
     /// use neighborgrid::*;
     /// let vec = vec![
-    ///     vec![0, 1, 2, 3],
-    ///     vec![4, 5, 6, 7],
-    ///     vec![8, 9, 10, 11],
-    ///     vec![12, 13, 14, 15],
-    ///     vec![16, 17, 18, 19],
+    ///             vec![0, 1, 2],
+    ///             vec![3, 4, 5],
     /// ];
-    /// let gridoptions = GridOptions {
-    ///     origin: Origin::UpperLeft,
-    ///     inverted_y: true,
-    ///     neighbor_ybased: false,
-    ///     ..GridOptions::default()
-    /// };
-    /// let mut grid = Grid::new(vec, Some(gridoptions)).expect("failed to import 2d vec");
-    /// let neighbors = grid
-    ///     .all_around_neighbors((0, 1))
-    ///     .expect("was not a valid coodinate"); // Neighbors of the item with 4 in it.
-    /// assert_eq!(neighbors.upleft, None);
-    /// assert_eq!(neighbors.up, Some(&0));
-    /// assert_eq!(neighbors.upright, Some(&1));
-    /// assert_eq!(neighbors.left, None);
-    /// assert_eq!(neighbors.right, Some(&5));
-    /// assert_eq!(neighbors.downleft, None);
-    /// assert_eq!(neighbors.down, Some(&8));
-    /// assert_eq!(neighbors.downright, Some(&9));
-    ///```
-    /// Using `GridOptions` to enable wrap:
-    ///
+    /// let grid = Grid::new(vec, None).expect("failed to import 2d vec");
+    /// assert_eq!(grid.get_row(1), Some(&[3, 4, 5][..]));
+    /// assert_eq!(grid.get_row(2), None);
+    /// ```
+    pub fn get_row(&self, row: usize) -> Option<&[T]> {
+        self.row_slices().nth(row)
+    }
+
+    /// Returns the cells of the internal column number `col`, zero based from the left of the grid regardless of `Origin`, from top to bottom.
+    /// `None` if `col` is not a column in the grid.
     /// ```
     /// use neighborgrid::*;
     /// let vec = vec![
-    ///     vec![0, 1, 2, 3],
-    ///     vec![4, 5, 6, 7],
-    ///     vec![8, 9, 10, 11],
-    ///     vec![12, 13, 14, 15],
-    ///     vec![16, 17, 18, 19],
+    ///             vec![0, 1, 2],
+    ///             vec![3, 4, 5],
     /// ];
-    /// let gridoptions = GridOptions {
-    ///     origin: Origin::UpperLeft,
-    ///     inverted_y: true,
-    ///     neighbor_ybased: false,
-    ///     wrap_x: true,
-    ///     wrap_y:true,
-    ///     ..GridOptions::default()
-    /// };
-    /// let mut grid = Grid::new(vec, Some(gridoptions)).expect("failed to import 2d vec");
-    /// let neighbors = grid
-    ///     .all_around_neighbors((0, 1))
-    ///     .expect("was not a valid coodinate"); // Neighbors of the item with 4 in it.
-    /// assert_eq!(neighbors.upleft, Some(&3));
-    /// assert_eq!(neighbors.up, Some(&0));
-    /// assert_eq!(neighbors.upright, Some(&1));
-    /// assert_eq!(neighbors.left, Some(&7));
-    /// assert_eq!(neighbors.right, Some(&5));
-    /// assert_eq!(neighbors.downleft, Some(&11));
-    /// assert_eq!(neighbors.down, Some(&8));
-    /// assert_eq!(neighbors.downright, Some(&9));
-    ///```
-    pub fn all_around_neighbors<I: Index>(
-        &self,
-        index: I,
-    ) -> Result<AllAroundNeighbor<'_, T>, GridError> {
-        let index = index.grid_index(self)?;
-        Ok(AllAroundNeighbor {
-            upleft: self.get_upleft(index),
-            up: self.get_up(index),
-            upright: self.get_upright(index),
-            left: self.get_left(index),
-            right: self.get_right(index),
-            downleft: self.get_downleft(index),
-            down: self.get_down(index),
-            downright: self.get_downright(index),
-        })
+    /// let grid = Grid::new(vec, None).expect("failed to import 2d vec");
+    /// assert_eq!(grid.get_col(1), Some(vec![&1, &4]));
+    /// assert_eq!(grid.get_col(3), None);
+    /// ```
+    pub fn get_col(&self, col: usize) -> Option<Vec<&T>> {
+        if col >= self.cols {
+            return None;
+        }
+        Some(ColIter::new(self, col).collect())
     }
 
-    pub(crate) fn create(
-        items: Vec<T>,
-        rows: usize,
-        cols: usize,
-        options: Option<GridOptions>,
-    ) -> Grid<T> {
-        Grid {
-            items,
-            rows,
-            cols,
-            options: options.unwrap_or_default(),
-        }
+    /// Returns an iterator that yields a `RowIter` for each row in the grid, from the top row to the bottom row.
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec![0, 1, 2],
+    ///             vec![3, 4, 5],
+    /// ];
+    /// let grid = Grid::new(vec, None).expect("failed to import 2d vec");
+    ///
+    /// let sums: Vec<i32> = grid.rows_iter().map(|row| row.sum()).collect();
+    /// assert_eq!(sums, vec![3, 12]);
+    /// ```
+    pub fn rows_iter(&self) -> impl Iterator<Item = RowIter<'_, T>> {
+        (0..self.rows).map(move |row| RowIter::new(self, row * self.cols))
     }
-    #[inline]
-    pub(crate) fn origin(&self) -> Origin {
-        self.options.origin.clone()
+
+    /// Returns an iterator that yields a `ColIter` for each column in the grid, from the left column to the right column.
+    pub fn cols_iter(&self) -> impl Iterator<Item = ColIter<'_, T>> {
+        (0..self.cols).map(move |col| ColIter::new(self, col))
     }
-}
 
-pub(crate) fn row_number<T>(grid: &Grid<T>, index: usize) -> usize {
-    index / grid.cols
-}
+    /// Consumes the grid and yields each row as a `Vec`, from the top row to the bottom row.  Collecting the rows produces a 2-D `Vec` that can be
+    /// turned back into a grid with `Grid::new`.
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec![0, 1, 2],
+    ///             vec![3, 4, 5],
+    /// ];
+    /// let grid = Grid::new(vec.clone(), None).expect("failed to import 2d vec");
+    /// assert_eq!(grid.into_rows().collect::<Vec<_>>(), vec);
+    /// ```
+    pub fn into_rows(self) -> impl Iterator<Item = Vec<T>> {
+        let cols = self.cols;
+        let mut items = self.items.into_iter();
+        (0..self.rows).map(move |_| items.by_ref().take(cols).collect())
+    }
 
-pub(crate) fn col_number<T>(grid: &Grid<T>, index: usize) -> usize {
-    index % grid.cols
-}
+    /// Copies the grid into a 2-D `Vec`, with each inner `Vec` being a row from the top row to the bottom row.  The opposite of `Grid::new` with a 2-D `Vec`.
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec![0, 1, 2],
+    ///             vec![3, 4, 5],
+    /// ];
+    /// let grid = Grid::new(vec.clone(), None).expect("failed to import 2d vec");
+    /// assert_eq!(grid.to_2d_vec(), vec);
+    /// ```
+    pub fn to_2d_vec(&self) -> Vec<Vec<T>>
+    where
+        T: Clone,
+    {
+        self.row_slices().map(|row| row.to_vec()).collect()
+    }
 
-pub(crate) fn row_start_index<T>(grid: &Grid<T>, index: usize) -> usize {
-    row_number(grid, index) * grid.cols
-}
+    /// Returns true if both grids show the same picture, even if they have different `GridOptions`, such as an `UpperLeft` origin with `inverted_y`
+    /// and a `LowerLeft` origin without.  Cells are always stored with the top row of the picture first, and the `Origin` and `inverted_y` only change
+    /// which coordinate refers to which cell, so this compares the cells top left first, the same as `same_layout`.
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec!['#', '.'],
+    ///             vec!['.', '.'],
+    /// ];
+    /// let upper_left = Grid::new(vec.clone(), None).expect("failed to import 2d vec");
+    /// let gridoptions = GridOptions::default().with_origin(Origin::LowerLeft).with_inverted_y(false);
+    /// let lower_left = Grid::new(vec, Some(gridoptions)).expect("failed to import 2d vec");
+    ///
+    /// // The top left cell has a different coordinate, but is the same cell of the picture
+    /// assert_eq!(upper_left.get((0, 0)), lower_left.get((0, 1)));
+    /// assert!(upper_left.visually_eq(&lower_left));
+    /// ```
+    pub fn visually_eq(&self, other: &Grid<T>) -> bool
+    where
+        T: PartialEq,
+    {
+        self.same_layout(other)
+    }
 
-pub(crate) fn col_start_index<T>(grid: &Grid<T>, index: usize) -> usize {
-    col_number(grid, index)
-}
+    /// The number of up, down, left and right moves needed to get from cell `a` to cell `b`.  When the grid wraps, the shorter way around is used.
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec![0, 1, 2, 3, 4],
+    ///             vec![5, 6, 7, 8, 9],
+    /// ];
+    /// let grid = Grid::new(vec.clone(), None).expect("failed to import 2d vec");
+    /// assert_eq!(grid.manhattan_distance((0, 0), (4, 1)).expect("invalid coordinate"), 5);
+    ///
+    /// let gridoptions = GridOptions::default().with_wrap(true, false);
+    /// let grid = Grid::new(vec, Some(gridoptions)).expect("failed to import 2d vec");
+    /// assert_eq!(grid.manhattan_distance((0, 0), (4, 1)).expect("invalid coordinate"), 2);
+    /// ```
+    pub fn manhattan_distance<I: GridIndex>(&self, a: I, b: I) -> Result<usize, GridError> {
+        let (dx, dy) = self.distance_deltas(a.grid_index(self)?, b.grid_index(self)?);
+        Ok(dx + dy)
+    }
 
-pub(crate) fn ceiling(a: usize, b: usize) -> usize {
-    (a + b - 1) / b
-}
+    /// The number of moves needed to get from cell `a` to cell `b`, when diagonal moves are allowed.  When the grid wraps, the shorter way around is used.
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec![0, 1, 2, 3, 4],
+    ///             vec![5, 6, 7, 8, 9],
+    /// ];
+    /// let grid = Grid::new(vec, None).expect("failed to import 2d vec");
+    /// assert_eq!(grid.chebyshev_distance((0, 0), (4, 1)).expect("invalid coordinate"), 4);
+    /// ```
+    pub fn chebyshev_distance<I: GridIndex>(&self, a: I, b: I) -> Result<usize, GridError> {
+        let (dx, dy) = self.distance_deltas(a.grid_index(self)?, b.grid_index(self)?);
+        Ok(dx.max(dy))
+    }
 
-#[cfg(test)]
-mod grid_tests {
+    /// The column and row distances between two internal vec indexes, taking the shorter way around when the grid wraps
+    pub(crate) fn distance_deltas(&self, a: usize, b: usize) -> (usize, usize) {
+        let mut dx = (a % self.cols).abs_diff(b % self.cols);
+        let mut dy = (a / self.cols).abs_diff(b / self.cols);
+        if self.options.wrap_x {
+            dx = dx.min(self.cols - dx);
+        }
+        if self.options.wrap_y {
+            dy = dy.min(self.rows - dy);
+        }
+        (dx, dy)
+    }
 
-    fn center_grid() -> Grid<i32> {
-        let vec = vec![
-            vec![0, 1, 2],
-            vec![3, 4, 5],
-            vec![6, 7, 8],
-            vec![9, 10, 11],
-            vec![12, 13, 14],
-        ];
-        let gridoptions = GridOptions {
-            origin: Origin::Center,
-            inverted_y: false,
-            ..GridOptions::default()
-        };
-        let grid = Grid::new(vec, Some(gridoptions));
-        grid.unwrap()
+    /// Renders the grid as text, with `f` converting each cell to a `char`.  Rows are separated by newlines, starting with the top row of the grid.
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec![true, false, true],
+    ///             vec![false, true, false],
+    /// ];
+    /// let grid = Grid::new(vec, None).expect("failed to import 2d vec");
+    /// assert_eq!(grid.render(|c| if *c { '#' } else { '.' }), "#.#\n.#.");
+    /// ```
+    pub fn render<F: Fn(&T) -> char>(&self, f: F) -> String {
+        self.row_slices()
+            .map(|row| row.iter().map(&f).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
     }
 
-    fn wrap_grid(wrap_x: bool, wrap_y: bool) -> Grid<i32> {
-        let vec = vec![
-            vec![0, 1, 2],
+    /// Renders the grid as text, with `f` converting each cell to a `String`, for cells that need more than one character.  The cells in each row are
+    /// joined with `separator`, and rows are separated by newlines, starting with the top row of the grid.
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec![1, 20, 3],
+    ///             vec![4, 5, 60],
+    /// ];
+    /// let grid = Grid::new(vec, None).expect("failed to import 2d vec");
+    /// assert_eq!(grid.render_with(|c| format!("{:>2}", c), " "), " 1 20  3\n 4  5 60");
+    /// ```
+    pub fn render_with<F: Fn(&T) -> String>(&self, f: F, separator: &str) -> String {
+        self.row_slices()
+            .map(|row| row.iter().map(&f).collect::<Vec<_>>().join(separator))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Returns true if both grids hold the same values in the same layout, ignoring the `GridOptions`.  `==` also compares the `GridOptions`, so two grids
+    /// that look the same but have a different `Origin`, for example, are not equal.
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec![0, 1, 2],
+    ///             vec![3, 4, 5],
+    /// ];
+    /// let gridoptions = GridOptions::default().with_origin(Origin::LowerLeft);
+    /// let a = Grid::new(vec.clone(), None).expect("failed to import 2d vec");
+    /// let b = Grid::new(vec, Some(gridoptions)).expect("failed to import 2d vec");
+    /// assert!(a.same_layout(&b));
+    /// assert_ne!(a, b);
+    /// ```
+    pub fn same_layout(&self, other: &Grid<T>) -> bool
+    where
+        T: PartialEq,
+    {
+        self.rows == other.rows && self.cols == other.cols && self.items == other.items
+    }
+
+    /// Creates a new grid by calling `f` on each pair of cells in the same position of this grid and `other`, such as to layer an overlay onto a map.
+    /// The new grid has the same `GridOptions` as this grid.  Returns `InvalidSize` if the grids do not have the same number of rows and columns.
+    /// ```
+    /// use neighborgrid::*;
+    /// let base = Grid::new(vec![vec![1, 2], vec![3, 4]], None).expect("failed to import 2d vec");
+    /// let overlay = Grid::new(vec![vec![None, Some(0)], vec![None, None]], None).expect("failed to import 2d vec");
+    /// let combined = base.zip_map(&overlay, |b, o| o.unwrap_or(*b)).expect("grids are different sizes");
+    /// assert_eq!(combined.to_2d_vec(), vec![vec![1, 0], vec![3, 4]]);
+    /// ```
+    pub fn zip_map<U, V, F: FnMut(&T, &U) -> V>(
+        &self,
+        other: &Grid<U>,
+        mut f: F,
+    ) -> Result<Grid<V>, GridError> {
+        if self.rows != other.rows || self.cols != other.cols {
+            return Err(GridError::InvalidSize);
+        }
+        let items = self
+            .items
+            .iter()
+            .zip(&other.items)
+            .map(|(a, b)| f(a, b))
+            .collect();
+        Ok(Grid::create(
+            items,
+            self.rows,
+            self.cols,
+            Some(self.options.clone()),
+        ))
+    }
+
+    /// Creates a new grid by calling `f` on each cell, in row major order, stopping at the first error and returning it.  Useful for parsing a grid of
+    /// text into numbers.  The new grid has the same dimensions and `GridOptions` as this grid.
+    /// ```
+    /// use neighborgrid::*;
+    /// let grid = Grid::new(vec![vec!["1", "2"], vec!["3", "4"]], None).expect("failed to import 2d vec");
+    /// let numbers = grid.try_map(|s| s.parse::<i32>()).expect("not a number");
+    /// assert_eq!(numbers.to_2d_vec(), vec![vec![1, 2], vec![3, 4]]);
+    ///
+    /// let grid = Grid::new(vec![vec!["1", "x"]], None).expect("failed to import 2d vec");
+    /// assert!(grid.try_map(|s| s.parse::<i32>()).is_err());
+    /// ```
+    pub fn try_map<U, E, F: FnMut(&T) -> Result<U, E>>(&self, f: F) -> Result<Grid<U>, E> {
+        let items = self.items.iter().map(f).collect::<Result<_, E>>()?;
+        Ok(Grid::create(
+            items,
+            self.rows,
+            self.cols,
+            Some(self.options.clone()),
+        ))
+    }
+
+    /// Advances the grid by one generation, such as in a cellular automaton.  `rule` is called for every cell with the current grid, the cell's coordinate,
+    /// and the cell's value, and returns the cell's value for the next generation.  Every cell sees the grid as it was before the step, and the new values
+    /// replace the old ones once all cells have been computed.
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec![0, 1, 0],
+    ///             vec![0, 1, 0],
+    ///             vec![0, 1, 0],
+    /// ];
+    /// let mut grid = Grid::new(vec, None).expect("failed to import 2d vec");
+    ///
+    /// // Conway's game of life
+    /// grid.step(|grid, coord, cell| {
+    ///     let alive = grid.all_around_neighbors(coord).unwrap().iter().filter(|n| **n == Some(&1)).count();
+    ///     match (cell, alive) {
+    ///         (1, 2) | (_, 3) => 1,
+    ///         _ => 0,
+    ///     }
+    /// });
+    /// assert_eq!(grid.to_2d_vec(), vec![vec![0, 0, 0], vec![1, 1, 1], vec![0, 0, 0]]);
+    /// ```
+    pub fn step<F: Fn(&Grid<T>, (isize, isize), &T) -> T>(&mut self, rule: F) {
+        let next = self
+            .items
+            .iter()
+            .enumerate()
+            .map(|(i, item)| rule(self, Index::output(i, self), item))
+            .collect();
+        self.items = next;
+    }
+
+    /// Calls `f` on every cell, in row major order, with the cell's coordinate and a mutable reference to its value.  The coordinate is based on the
+    /// `Origin` and other `GridOptions`.  Unlike `step`, the cells are updated in place, so no new buffer is allocated.
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec![0, 0, 0],
+    ///             vec![10, 10, 10],
+    /// ];
+    /// let mut grid = Grid::new(vec, None).expect("failed to import 2d vec");
+    /// grid.for_each_coord_mut(|(x, _), cell| *cell += x);
+    /// assert_eq!(grid.to_2d_vec(), vec![vec![0, 1, 2], vec![10, 11, 12]]);
+    /// ```
+    pub fn for_each_coord_mut<F: FnMut((isize, isize), &mut T)>(&mut self, mut f: F) {
+        for i in 0..self.items.len() {
+            let coord = Index::output(i, &*self);
+            f(coord, &mut self.items[i]);
+        }
+    }
+
+    /// Returns an iterator over the coordinate of every cell, in row major order.  The coordinates are based on the `Origin` and other `GridOptions`.
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec![0, 1],
+    ///             vec![2, 3],
+    /// ];
+    /// let grid = Grid::new(vec, None).expect("failed to import 2d vec");
+    /// let coords: Vec<_> = grid.coordinates().collect();
+    /// assert_eq!(coords, vec![(0, 0), (1, 0), (0, 1), (1, 1)]);
+    /// ```
+    pub fn coordinates(&self) -> impl Iterator<Item = (isize, isize)> + '_ {
+        (0..self.size()).map(move |i| Index::output(i, self))
+    }
+
+    /// Returns an iterator over the cells whose coordinate passes `mask`, along with the coordinate, in row major order.  Useful for working with a
+    /// region of the grid that is not rectangular.  Only iteration is restricted; neighbor methods still see every cell.
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec![0, 1, 2],
+    ///             vec![3, 4, 5],
+    /// ];
+    /// let grid = Grid::new(vec, None).expect("failed to import 2d vec");
+    /// let masked: Vec<_> = grid.iter_masked(|(x, _)| x != 1).collect();
+    /// assert_eq!(masked, vec![((0, 0), &0), ((2, 0), &2), ((0, 1), &3), ((2, 1), &5)]);
+    /// ```
+    pub fn iter_masked<'a, F: Fn((isize, isize)) -> bool + 'a>(
+        &'a self,
+        mask: F,
+    ) -> impl Iterator<Item = ((isize, isize), &'a T)> {
+        self.coordinates()
+            .zip(self.items.iter())
+            .filter(move |(coord, _)| mask(*coord))
+    }
+
+    /// Returns an iterator over one color of a checkerboard pattern, along with the coordinate of each cell, in row major order.  Cells where the sum of
+    /// the row and column in memory is even are yielded when `even` is true, and the rest when it is false, so the upper left cell in memory is always
+    /// even regardless of the `Origin`.  Useful for updating cells in two passes where neither pass reads a cell that it changes.
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec![0, 1, 2],
+    ///             vec![3, 4, 5],
+    /// ];
+    /// let grid = Grid::new(vec, None).expect("failed to import 2d vec");
+    /// let even: Vec<_> = grid.parity_iter(true).map(|(_, v)| *v).collect();
+    /// assert_eq!(even, vec![0, 2, 4]);
+    /// let odd: Vec<_> = grid.parity_iter(false).map(|(_, v)| *v).collect();
+    /// assert_eq!(odd, vec![1, 3, 5]);
+    /// ```
+    pub fn parity_iter(&self, even: bool) -> impl Iterator<Item = ((isize, isize), &T)> {
+        self.items
+            .iter()
+            .enumerate()
+            .filter(move |(i, _)| ((i / self.cols + i % self.cols) % 2 == 0) == even)
+            .map(|(i, item)| (Index::output(i, self), item))
+    }
+
+    /// Returns the coordinate of the first cell, in row major order, where `f` returns true.  The coordinate is based on the `Origin` and other `GridOptions`.
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec![0, 1, 2],
+    ///             vec![3, 4, 5],
+    ///             vec![6, 7, 8],
+    /// ];
+    /// let gridoptions = GridOptions {
+    ///        origin: Origin::Center,
+    ///        inverted_y: false,
+    ///        ..GridOptions::default()
+    /// };
+    /// let grid = Grid::new(vec, Some(gridoptions)).expect("failed to import 2d vec");
+    /// assert_eq!(grid.position(|v| *v > 4), Some((1, 0)));
+    /// assert_eq!(grid.position(|v| *v > 8), None);
+    /// ```
+    pub fn position<F: Fn(&T) -> bool>(&self, f: F) -> Option<(isize, isize)> {
+        self.items
+            .iter()
+            .position(f)
+            .map(|i| Index::output(i, self))
+    }
+
+    /// Returns the coordinates of all the cells, in row major order, where `f` returns true.  The coordinates are based on the `Origin` and other `GridOptions`.
+    pub fn positions<F: Fn(&T) -> bool>(&self, f: F) -> Vec<(isize, isize)> {
+        self.items
+            .iter()
+            .enumerate()
+            .filter(|(_, v)| f(v))
+            .map(|(i, _)| Index::output(i, self))
+            .collect()
+    }
+
+    /// Returns the coordinate and value of the cell with the largest key returned by `f`.  If several cells are equally large, the first one in row
+    /// major order is returned.  The coordinate is based on the `Origin` and other `GridOptions`.
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec![0, 9, 2],
+    ///             vec![3, 4, 9],
+    /// ];
+    /// let grid = Grid::new(vec, None).expect("failed to import 2d vec");
+    /// assert_eq!(grid.max_by_key(|v| *v), Some(((1, 0), &9)));
+    /// assert_eq!(grid.min_by_key(|v| *v), Some(((0, 0), &0)));
+    /// ```
+    pub fn max_by_key<K: Ord, F: Fn(&T) -> K>(&self, f: F) -> Option<((isize, isize), &T)> {
+        self.extreme_by_key(f, |new, best| new > best)
+    }
+
+    /// Returns the coordinate and value of the cell with the smallest key returned by `f`.  If several cells are equally small, the first one in row
+    /// major order is returned.  The coordinate is based on the `Origin` and other `GridOptions`.
+    pub fn min_by_key<K: Ord, F: Fn(&T) -> K>(&self, f: F) -> Option<((isize, isize), &T)> {
+        self.extreme_by_key(f, |new, best| new < best)
+    }
+
+    /// Keeps the first cell in row major order, only replacing it when `replace` is true
+    fn extreme_by_key<K, F, R>(&self, f: F, replace: R) -> Option<((isize, isize), &T)>
+    where
+        F: Fn(&T) -> K,
+        R: Fn(&K, &K) -> bool,
+    {
+        let mut best: Option<(usize, K)> = None;
+        for (i, item) in self.items.iter().enumerate() {
+            let key = f(item);
+            match &best {
+                Some((_, best_key)) if !replace(&key, best_key) => {}
+                _ => best = Some((i, key)),
+            }
+        }
+        best.map(|(i, _)| (Index::output(i, self), &self.items[i]))
+    }
+
+    /// The number of cells where `f` returns true
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec![0, 1, 2],
+    ///             vec![3, 4, 5],
+    /// ];
+    /// let grid = Grid::new(vec, None).expect("failed to import 2d vec");
+    /// assert_eq!(grid.count(|v| v % 2 == 0), 3);
+    /// ```
+    pub fn count<F: Fn(&T) -> bool>(&self, f: F) -> usize {
+        self.items.iter().filter(|v| f(v)).count()
+    }
+
+    /// The number of cells that are equal to `value`
+    pub fn count_eq(&self, value: &T) -> usize
+    where
+        T: PartialEq,
+    {
+        self.count(|v| v == value)
+    }
+
+    /// Counts how many cells hold each distinct value
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec!['~', '~', '#'],
+    ///             vec!['~', '#', '#'],
+    /// ];
+    /// let grid = Grid::new(vec, None).expect("failed to import 2d vec");
+    /// let histogram = grid.histogram();
+    /// assert_eq!(histogram[&'~'], 3);
+    /// assert_eq!(histogram.len(), 2);
+    /// ```
+    pub fn histogram(&self) -> std::collections::HashMap<T, usize>
+    where
+        T: Eq + std::hash::Hash + Clone,
+    {
+        let mut histogram = std::collections::HashMap::new();
+        for item in &self.items {
+            *histogram.entry(item.clone()).or_insert(0) += 1;
+        }
+        histogram
+    }
+
+    /// The sum of every cell
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec![0, 1, 2],
+    ///             vec![3, 4, 5],
+    /// ];
+    /// let grid = Grid::new(vec, None).expect("failed to import 2d vec");
+    /// assert_eq!(grid.sum(), 15);
+    /// ```
+    pub fn sum(&self) -> T
+    where
+        T: std::iter::Sum<T> + Clone,
+    {
+        self.items.iter().cloned().sum()
+    }
+
+    /// The product of every cell
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec![1, 2, 3],
+    ///             vec![4, 5, 6],
+    /// ];
+    /// let grid = Grid::new(vec, None).expect("failed to import 2d vec");
+    /// assert_eq!(grid.product(), 720);
+    /// ```
+    pub fn product(&self) -> T
+    where
+        T: std::iter::Product<T> + Clone,
+    {
+        self.items.iter().cloned().product()
+    }
+
+    /// Folds every cell into an accumulator, in row major order, starting with `init`
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec![0, 1, 2],
+    ///             vec![3, 4, 5],
+    /// ];
+    /// let grid = Grid::new(vec, None).expect("failed to import 2d vec");
+    /// assert_eq!(grid.fold(String::new(), |acc, v| acc + &v.to_string()), "012345");
+    /// ```
+    pub fn fold<B, F: FnMut(B, &T) -> B>(&self, init: B, f: F) -> B {
+        self.items.iter().fold(init, f)
+    }
+
+    /// Swap two cells with each other.  Returns `DuplicateIndex` if both refer to the same cell, and the grid is left unchanged.
+    pub fn swap<I: GridIndex>(&mut self, a: I, b: I) -> Result<(), GridError> {
+        let a = a.grid_index(self)?;
+        let b = b.grid_index(self)?;
+        if a == b {
+            return Err(GridError::DuplicateIndex);
+        }
+        self.items.swap(a, b);
+        Ok(())
+    }
+
+    pub fn row_iter_mut<'b, 'a: 'b, I: GridIndex>(&'a mut self, index: I) -> MutRowIter<'b, T> {
+        let res = index.grid_index(self);
+        // Noop coverts invalid grid location Result into an iterator that returns None right way
+        match res {
+            Ok(i) => MutRowIter::new(self, i),
+            Err(_) => MutRowIter::noop(),
+        }
+    }
+
+    pub fn col_iter_mut<'b, 'a: 'b, I: GridIndex>(&'a mut self, index: I) -> MutColIter<'b, T> {
+        let res = index.grid_index(self);
+        // Noop coverts invalid grid location Result into an iterator that returns None right way
+        match res {
+            Ok(i) => MutColIter::new(self, i),
+            Err(_) => MutColIter::noop(),
+        }
+    }
+
+    /// Returns an `nrant_iter` with a divisor of 2.  Hence, the grid is split into 4 quadrants and iterates over the quadrant that the
+    /// index belongs to, from the start of the quadrant to the end of the quadrant.
+    pub fn quadrant_iter<'b, 'a: 'b, I: GridIndex>(&'a self, index: I) -> NrantIterator<'b, T> {
+        self.nrant_iter(2, index)
+    }
+
+    /// Divides the grid, as specified by `divisor`, and produces `divisor * divisor` amount of sections.  For example, if `divisor` is `2`, then
+    /// the grid is divided into quadrants.  Making a Sudoku puzzle and want 9 sections (each 3x3) for your 9x9 grid?  Use a `divisor` of `3`.
+    /// ```
+    /// use neighborgrid::*;
+    /// let mut vec = vec![];
+    ///
+    ///for i in 1..=81 {
+    ///    vec.push(i);
+    ///}
+    ///
+    ///let grid = Grid::new_from_1d(vec, 9, 9, None).unwrap();
+    ///
+    ///let mut iter = grid.nrant_iter(3, (1,1));
+    ///assert_eq!(iter.next(), Some(Some(&1)));
+    ///assert_eq!(iter.next(), Some(Some(&2)));
+    ///assert_eq!(iter.next(), Some(Some(&3)));
+    ///assert_eq!(iter.next(), Some(Some(&10)));
+    ///assert_eq!(iter.next(), Some(Some(&11)));
+    ///assert_eq!(iter.next(), Some(Some(&12)));
+    ///assert_eq!(iter.next(), Some(Some(&19)));
+    ///assert_eq!(iter.next(), Some(Some(&20)));
+    ///assert_eq!(iter.next(), Some(Some(&21)));
+    ///assert_eq!(iter.next(), None);
+    ///```
+    pub fn nrant_iter<'b, 'a: 'b, I: GridIndex>(
+        &'a self,
+        divisor: usize,
+        index: I,
+    ) -> NrantIterator<'b, T> {
+        let res = index.grid_index(self);
+        // Noop coverts invalid grid location Result into an iterator that returns None right way
+        match res {
+            Ok(i) => NrantIterator::new(self, divisor, i),
+            Err(_) => NrantIterator::noop(self),
+        }
+    }
+    /// Returns an iterator over the cells along a straight line from `from` to `to`, inclusive of both ends.  The cells are chosen with Bresenham's line
+    /// algorithm.  Returns `IndexOutOfBounds` if either end is not in the grid.
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec![0, 1, 2],
+    ///             vec![3, 4, 5],
+    ///             vec![6, 7, 8],
+    /// ];
+    /// let grid = Grid::new(vec, None).expect("failed to import 2d vec");
+    ///
+    /// let mut iter = grid.line_iter((0, 0), (2, 2)).expect("invalid coordinate");
+    /// assert_eq!(iter.next(), Some(&0));
+    /// assert_eq!(iter.next(), Some(&4));
+    /// assert_eq!(iter.next(), Some(&8));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    pub fn line_iter<I: GridIndex>(&self, from: I, to: I) -> Result<LineIter<'_, T>, GridError> {
+        let from = from.grid_index(self)?;
+        let to = to.grid_index(self)?;
+        Ok(LineIter::new(self, from, to))
+    }
+
+    /// Returns an iterator over the cells that are exactly `radius` cells away from the specified cell, including diagonally, which forms a square ring.
+    /// Iteration is clockwise, starting at the upper left corner of the ring, where "up" follows the same rules as `get_up`.  A `radius` of `1` returns
+    /// the same cells as `all_around_neighbors`, and a `radius` of `0` returns only the specified cell.
+    ///
+    /// Like the neighbor methods, positions off the edge of the grid are `None` unless `wrap_x` and / or `wrap_y` are enabled in the `GridOptions`.  If
+    /// neither axis wraps, a ring that is entirely off the grid returns an empty iterator, so every ring that is not empty has at least one cell.
+    /// Otherwise the ring always has `8 * radius` positions, and `ExcessiveSize` is returned if that is more than an `isize` can hold.
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec![0, 1, 2],
+    ///             vec![3, 4, 5],
+    ///             vec![6, 7, 8],
+    /// ];
+    /// let gridoptions = GridOptions {
+    ///        neighbor_ybased: false,
+    ///        ..GridOptions::default()
+    /// };
+    /// let grid = Grid::new(vec, Some(gridoptions)).expect("failed to import 2d vec");
+    ///
+    /// let ring: Vec<_> = grid.ring_iter((1, 1), 1).expect("invalid coordinate").flatten().collect();
+    /// assert_eq!(ring, vec![&0, &1, &2, &5, &8, &7, &6, &3]);
+    /// ```
+    pub fn ring_iter<I: GridIndex>(
+        &self,
+        index: I,
+        radius: usize,
+    ) -> Result<RingIter<'_, T>, GridError> {
+        let index = index.grid_index(self)?;
+        RingIter::new(self, index, radius)
+    }
+
+    /// Iterates over the cells on the edge of the grid, each exactly once, going clockwise in memory order: the first row left to right, the last
+    /// column, the last row right to left, and then the first column back towards the start.  A grid that is a single row or column yields each cell once.
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec![0, 1, 2],
+    ///             vec![3, 4, 5],
+    ///             vec![6, 7, 8],
+    /// ];
+    /// let grid = Grid::new(vec, None).expect("failed to import 2d vec");
+    ///
+    /// let boundary: Vec<_> = grid.boundary_iter().collect();
+    /// assert_eq!(boundary, vec![&0, &1, &2, &5, &8, &7, &6, &3]);
+    /// ```
+    pub fn boundary_iter(&self) -> impl Iterator<Item = &T> {
+        let (rows, cols) = (self.rows, self.cols);
+        let top = 0..cols;
+        let right = (1..rows).map(move |row| row * cols + cols - 1);
+        let bottom = (0..cols.saturating_sub(1))
+            .rev()
+            .filter(move |_| rows > 1)
+            .map(move |col| (rows - 1) * cols + col);
+        let left = (1..rows.saturating_sub(1))
+            .rev()
+            .filter(move |_| cols > 1)
+            .map(move |row| row * cols);
+        top.chain(right)
+            .chain(bottom)
+            .chain(left)
+            .map(|i| &self.items[i])
+    }
+
+    /// Iterates over the cells that are not on the edge of the grid, in row major order.  These are the cells that have all eight neighbors without
+    /// wrapping.  A grid with fewer than three rows or columns has no interior cells.
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec![0, 1, 2, 3],
+    ///             vec![4, 5, 6, 7],
+    ///             vec![8, 9, 10, 11],
+    /// ];
+    /// let grid = Grid::new(vec, None).expect("failed to import 2d vec");
+    ///
+    /// let interior: Vec<_> = grid.interior_iter().collect();
+    /// assert_eq!(interior, vec![&5, &6]);
+    /// ```
+    pub fn interior_iter(&self) -> impl Iterator<Item = &T> {
+        let rows = if self.cols < 3 {
+            0..0
+        } else {
+            1..self.rows.saturating_sub(1)
+        };
+        rows.flat_map(move |row| {
+            let start = row * self.cols;
+            &self.items[start + 1..start + self.cols - 1]
+        })
+    }
+
+    /// Visits every `col_step`th column of every `row_step`th row, starting with the first cell in memory, in row major order.  Useful for building a
+    /// thumbnail of a large grid.  A step of zero is treated as a step of one.
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec![0, 1, 2],
+    ///             vec![3, 4, 5],
+    ///             vec![6, 7, 8],
+    /// ];
+    /// let grid = Grid::new(vec, None).expect("failed to import 2d vec");
+    ///
+    /// let corners: Vec<_> = grid.sample(2, 2).collect();
+    /// assert_eq!(corners, vec![&0, &2, &6, &8]);
+    /// ```
+    pub fn sample(&self, col_step: usize, row_step: usize) -> impl Iterator<Item = &T> {
+        let (col_step, row_step) = (col_step.max(1), row_step.max(1));
+        self.row_slices()
+            .step_by(row_step)
+            .flat_map(move |row| row.iter().step_by(col_step))
+    }
+
+    /// Slides a `width` x `height` window across the grid, left to right and then top to bottom.  Each window is returned as a `Vec` of the cells in the
+    /// window, in row major order.  A grid will produce `(columns - width + 1) * (rows - height + 1)` windows, and no windows if the window does not fit in the grid.
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec![0, 1, 2],
+    ///             vec![3, 4, 5],
+    ///             vec![6, 7, 8],
+    /// ];
+    /// let grid = Grid::new(vec, None).expect("failed to import 2d vec");
+    ///
+    /// let mut windows = grid.windows(2, 2);
+    /// assert_eq!(windows.next(), Some(vec![&0, &1, &3, &4]));
+    /// assert_eq!(windows.next(), Some(vec![&1, &2, &4, &5]));
+    /// assert_eq!(windows.next(), Some(vec![&3, &4, &6, &7]));
+    /// assert_eq!(windows.next(), Some(vec![&4, &5, &7, &8]));
+    /// assert_eq!(windows.next(), None);
+    /// ```
+    pub fn windows(&self, width: usize, height: usize) -> impl Iterator<Item = Vec<&T>> {
+        let (x_count, y_count) =
+            if width == 0 || height == 0 || width > self.cols || height > self.rows {
+                (0, 0)
+            } else {
+                (self.cols - width + 1, self.rows - height + 1)
+            };
+        (0..y_count)
+            .flat_map(move |top| (0..x_count).map(move |left| self.block(left, top, width, height)))
+    }
+
+    /// Creates a new grid where each cell is `f` applied to the `width` x `height` window centered on that cell, such as for blurring an image.  The
+    /// window is passed in row major order, and the parts of it that are off the edge of the grid are filled with the default value of `T`.  Every cell
+    /// is computed from this grid, so changes never feed into the windows of later cells.  The new grid keeps this grid's options.  Returns
+    /// `InvalidSize` if `width` or `height` is not odd, as the window would have no center, and `ExcessiveSize` if the window is wider than
+    /// `2 * columns + 1` or taller than `2 * rows + 1`, as the extra cells could only ever be padding.
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec![0, 0, 0],
+    ///             vec![0, 9, 0],
+    ///             vec![0, 0, 0],
+    /// ];
+    /// let grid = Grid::new(vec, None).expect("failed to import 2d vec");
+    /// let spread = grid.convolve(3, 1, |window| window.iter().copied().sum()).expect("window has no center");
+    /// assert_eq!(spread.to_2d_vec(), vec![vec![0, 0, 0], vec![9, 9, 9], vec![0, 0, 0]]);
+    /// ```
+    pub fn convolve<F: Fn(&[&T]) -> T>(
+        &self,
+        width: usize,
+        height: usize,
+        f: F,
+    ) -> Result<Grid<T>, GridError>
+    where
+        T: Default,
+    {
+        if width % 2 == 0 || height % 2 == 0 {
+            return Err(GridError::InvalidSize);
+        }
+        if width > self.cols.saturating_mul(2).saturating_add(1)
+            || height > self.rows.saturating_mul(2).saturating_add(1)
+        {
+            return Err(GridError::ExcessiveSize);
+        }
+        let capacity = width.checked_mul(height).ok_or(GridError::ExcessiveSize)?;
+        let padding = T::default();
+        let (half_width, half_height) = ((width / 2) as isize, (height / 2) as isize);
+        let (rows, cols) = (self.rows as isize, self.cols as isize);
+        let mut window = Vec::with_capacity(capacity);
+        let items = (0..self.size())
+            .map(|i| {
+                let (col, row) = ((i % self.cols) as isize, (i / self.cols) as isize);
+                window.clear();
+                for y in row - half_height..=row + half_height {
+                    for x in col - half_width..=col + half_width {
+                        window.push(if x < 0 || y < 0 || x >= cols || y >= rows {
+                            &padding
+                        } else {
+                            &self.items[(y * cols + x) as usize]
+                        });
+                    }
+                }
+                f(&window)
+            })
+            .collect();
+        Ok(Grid::create(
+            items,
+            self.rows,
+            self.cols,
+            Some(self.options.clone()),
+        ))
+    }
+
+    /// Splits the grid into tiles of `block_width` x `block_height` cells, left to right and then top to bottom.  Each tile is returned as a `Vec` of the
+    /// cells in the tile, in row major order.  Unlike `nrant_iter`, every tile is the same size, so the grid's columns must be evenly divisible by
+    /// `block_width` and its rows by `block_height`, otherwise `InvalidSize` is returned.
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec![0, 1, 2, 3],
+    ///             vec![4, 5, 6, 7],
+    /// ];
+    /// let grid = Grid::new(vec, None).expect("failed to import 2d vec");
+    ///
+    /// let mut tiles = grid.tiles(2, 2).expect("grid is not divisible into tiles");
+    /// assert_eq!(tiles.next(), Some(vec![&0, &1, &4, &5]));
+    /// assert_eq!(tiles.next(), Some(vec![&2, &3, &6, &7]));
+    /// assert_eq!(tiles.next(), None);
+    /// ```
+    pub fn tiles(
+        &self,
+        block_width: usize,
+        block_height: usize,
+    ) -> Result<impl Iterator<Item = Vec<&T>>, GridError> {
+        if block_width == 0
+            || block_height == 0
+            || self.cols % block_width != 0
+            || self.rows % block_height != 0
+        {
+            return Err(GridError::InvalidSize);
+        }
+        let (x_count, y_count) = (self.cols / block_width, self.rows / block_height);
+        Ok((0..y_count).flat_map(move |tile_row| {
+            (0..x_count).map(move |tile_col| {
+                self.block(
+                    tile_col * block_width,
+                    tile_row * block_height,
+                    block_width,
+                    block_height,
+                )
+            })
+        }))
+    }
+
+    /// Builds a smaller grid where each cell is `agg` applied to one `block_width` x `block_height` tile of this grid, as laid out by `tiles`.  The
+    /// new grid keeps this grid's options.  Returns `InvalidSize` if the grid is not evenly divisible into tiles of that size.
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec![1, 3, 0, 0],
+    ///             vec![5, 7, 2, 2],
+    /// ];
+    /// let grid = Grid::new(vec, None).expect("failed to import 2d vec");
+    ///
+    /// let small = grid.downsample(2, 2, |block| **block.iter().max().unwrap()).expect("grid is not divisible into tiles");
+    /// assert_eq!(small.to_2d_vec(), vec![vec![7, 2]]);
+    /// ```
+    pub fn downsample<F: Fn(&[&T]) -> T>(
+        &self,
+        block_width: usize,
+        block_height: usize,
+        agg: F,
+    ) -> Result<Grid<T>, GridError> {
+        let items = self
+            .tiles(block_width, block_height)?
+            .map(|tile| agg(&tile))
+            .collect();
+        Ok(Grid::create(
+            items,
+            self.rows / block_height,
+            self.cols / block_width,
+            Some(self.options.clone()),
+        ))
+    }
+
+    /// Returns the coordinates of every cell in the nrant that `index` belongs to, in row major order.  See `nrant_iter` for how the grid is divided.
+    /// Unlike `nrant_iter`, positions that overrun the edge of the grid are not included.
+    /// ```
+    /// use neighborgrid::*;
+    /// let grid = Grid::new_from_1d((1..=81).collect(), 9, 9, None).unwrap();
+    ///
+    /// let coords = grid.nrant_coords(3, (4, 1)).expect("invalid coordinate or divisor");
+    /// assert_eq!(coords.len(), 9);
+    /// assert_eq!(coords[0], (3, 0));
+    /// assert_eq!(coords[8], (5, 2));
+    /// ```
+    pub fn nrant_coords<I: GridIndex>(
+        &self,
+        divisor: usize,
+        index: I,
+    ) -> Result<Vec<(isize, isize)>, GridError> {
+        let index = index.grid_index(self)?;
+        self.nrant(index, divisor)?;
+        let start = self.nrant_start(index, divisor);
+        let rwidth = ceiling(self.columns(), divisor);
+        let rheight = ceiling(self.rows(), divisor);
+        let (start_col, start_row) = (start % self.cols, start / self.cols);
+        let cols = start_col..std::cmp::min(start_col + rwidth, self.cols);
+        let rows = start_row..std::cmp::min(start_row + rheight, self.rows);
+        Ok(rows
+            .flat_map(|row| cols.clone().map(move |col| row * self.cols + col))
+            .map(|i| Index::output(i, self))
+            .collect())
+    }
+
+    /// Returns an `XyNeighbor` which are the four neighbors in cardinal directions from the called cell location
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec![0, 1, 2],
+    ///             vec![3, 4, 5],
+    ///             vec![6, 7, 8],
+    ///             vec![9, 10, 11],
+    ///             vec![12, 13, 14],
+    /// ];
+    /// let gridoptions = GridOptions {
+    ///        origin: Origin::Center,
+    ///        inverted_y: false,
+    ///        ..GridOptions::default()
+    /// };
+    /// let mut grid = Grid::new(vec, Some(gridoptions)).expect("failed to import 2d vec");
+    ///
+    /// let neighbors = grid.xy_neighbors((-1,-2)).expect("was not a valid coodinate"); // Neighbors of the item with 12 in it.
+    /// assert_eq!(neighbors.up, Some(&9));
+    /// assert_eq!(neighbors.down, None);
+    /// assert_eq!(neighbors.left, None);
+    /// assert_eq!(neighbors.right, Some(&13));
+    ///```
+    /// If `GridOptions` `x_wrap` and / or `y_wrap` are true, then the wrapped neighbors will be returned
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec![0, 1, 2],
+    ///             vec![3, 4, 5],
+    ///             vec![6, 7, 8],
+    ///             vec![9, 10, 11],
+    ///             vec![12, 13, 14],
+    /// ];
+    /// let gridoptions = GridOptions {
+    ///        origin: Origin::Center,
+    ///        inverted_y: false,
+    ///        wrap_x: true, // turn on wrapping
+    ///        wrap_y: true,
+    ///        ..GridOptions::default()
+    /// };
+    /// let mut grid = Grid::new(vec, Some(gridoptions)).expect("failed to import 2d vec");
+    ///
+    /// let neighbors = grid.xy_neighbors((-1,-2)).expect("was not a valid coodinate"); // Neighbors of the item with 12 in it.
+    /// assert_eq!(neighbors.up, Some(&9));
+    /// assert_eq!(neighbors.down, Some(&0));
+    /// assert_eq!(neighbors.left, Some(&14));
+    /// assert_eq!(neighbors.right, Some(&13));
+    ///```
+    pub fn xy_neighbors<I: GridIndex>(&self, index: I) -> Result<XyNeighbor<'_, T>, GridError> {
+        let index = index.grid_index(self)?;
+        Ok(XyNeighbor {
+            up: self.get_up(index),
+            left: self.get_left(index),
+            right: self.get_right(index),
+            down: self.get_down(index),
+        })
+    }
+
+    /// Same as `xy_neighbors`, but `wrap_x` and `wrap_y` are used instead of the wrap settings in the grid's `GridOptions`.  Useful when only some
+    /// neighbor lookups on a grid should wrap.
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec![0, 1, 2],
+    ///             vec![3, 4, 5],
+    ///             vec![6, 7, 8],
+    /// ];
+    /// let gridoptions = GridOptions {
+    ///        neighbor_ybased: false,
+    ///        ..GridOptions::default()
+    /// };
+    /// let grid = Grid::new(vec, Some(gridoptions)).expect("failed to import 2d vec");
+    ///
+    /// let neighbors = grid.xy_neighbors_wrapped((0, 0), true, false).expect("was not a valid coodinate");
+    /// assert_eq!(neighbors.left, Some(&2));
+    /// assert_eq!(neighbors.up, None);
+    /// ```
+    pub fn xy_neighbors_wrapped<I: GridIndex>(
+        &self,
+        index: I,
+        wrap_x: bool,
+        wrap_y: bool,
+    ) -> Result<XyNeighbor<'_, T>, GridError> {
+        let index = index.grid_index(self)?;
+        let get = |idx: Result<usize, GridError>| idx.ok().map(|i| &self.items[i]);
+        Ok(XyNeighbor {
+            up: get(self.up_idx_wrap(index, wrap_y)),
+            left: get(self.left_idx_wrap(index, wrap_x)),
+            right: get(self.right_idx_wrap(index, wrap_x)),
+            down: get(self.down_idx_wrap(index, wrap_y)),
+        })
+    }
+
+    /// Returns the coordinates of the four cardinal neighbors of the specified cell, in the order `[up, left, right, down]`.  Neighbors that fall off
+    /// a non-wrapping edge are `None`.  Useful when only the location of the neighbors is needed, such as when building a pathfinding frontier.
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec![0, 1, 2],
+    ///             vec![3, 4, 5],
+    ///             vec![6, 7, 8],
+    /// ];
+    /// let gridoptions = GridOptions {
+    ///        neighbor_ybased: false,
+    ///        ..GridOptions::default()
+    /// };
+    /// let grid = Grid::new(vec, Some(gridoptions)).expect("failed to import 2d vec");
+    /// let coords = grid.xy_neighbor_coords((1, 1)).expect("was not a valid coodinate");
+    /// assert_eq!(coords, [Some((1, 0)), Some((0, 1)), Some((2, 1)), Some((1, 2))]);
+    /// ```
+    pub fn xy_neighbor_coords<I: GridIndex>(
+        &self,
+        index: I,
+    ) -> Result<[Option<(isize, isize)>; 4], GridError> {
+        let index = index.grid_index(self)?;
+        let coord = |idx: Result<usize, GridError>| idx.ok().map(|i| Index::output(i, self));
+        Ok([
+            coord(self.up_idx(index)),
+            coord(self.left_idx(index)),
+            coord(self.right_idx(index)),
+            coord(self.down_idx(index)),
+        ])
+    }
+
+    /// Returns an `AllAroundNeighbor` of the neighbors of the specified cell. Order is left, right, bottom, top of index called.
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///     vec![0, 1, 2, 3],
+    ///     vec![4, 5, 6, 7],
+    ///     vec![8, 9, 10, 11],
+    ///     vec![12, 13, 14, 15],
+    ///     vec![16, 17, 18, 19],
+    /// ];
+    /// let gridoptions = GridOptions {
+    ///     origin: Origin::UpperLeft,
+    ///     inverted_y: true,
+    ///     neighbor_ybased: false,
+    ///     ..GridOptions::default()
+    /// };
+    /// let mut grid = Grid::new(vec, Some(gridoptions)).expect("failed to import 2d vec");
+    /// let neighbors = grid
+    ///     .all_around_neighbors((0, 1))
+    ///     .expect("was not a valid coodinate"); // Neighbors of the item with 4 in it.
+    /// assert_eq!(neighbors.upleft, None);
+    /// assert_eq!(neighbors.up, Some(&0));
+    /// assert_eq!(neighbors.upright, Some(&1));
+    /// assert_eq!(neighbors.left, None);
+    /// assert_eq!(neighbors.right, Some(&5));
+    /// assert_eq!(neighbors.downleft, None);
+    /// assert_eq!(neighbors.down, Some(&8));
+    /// assert_eq!(neighbors.downright, Some(&9));
+    ///```
+    /// Using `GridOptions` to enable wrap:
+    ///
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///     vec![0, 1, 2, 3],
+    ///     vec![4, 5, 6, 7],
+    ///     vec![8, 9, 10, 11],
+    ///     vec![12, 13, 14, 15],
+    ///     vec![16, 17, 18, 19],
+    /// ];
+    /// let gridoptions = GridOptions {
+    ///     origin: Origin::UpperLeft,
+    ///     inverted_y: true,
+    ///     neighbor_ybased: false,
+    ///     wrap_x: true,
+    ///     wrap_y:true,
+    ///     ..GridOptions::default()
+    /// };
+    /// let mut grid = Grid::new(vec, Some(gridoptions)).expect("failed to import 2d vec");
+    /// let neighbors = grid
+    ///     .all_around_neighbors((0, 1))
+    ///     .expect("was not a valid coodinate"); // Neighbors of the item with 4 in it.
+    /// assert_eq!(neighbors.upleft, Some(&3));
+    /// assert_eq!(neighbors.up, Some(&0));
+    /// assert_eq!(neighbors.upright, Some(&1));
+    /// assert_eq!(neighbors.left, Some(&7));
+    /// assert_eq!(neighbors.right, Some(&5));
+    /// assert_eq!(neighbors.downleft, Some(&11));
+    /// assert_eq!(neighbors.down, Some(&8));
+    /// assert_eq!(neighbors.downright, Some(&9));
+    ///```
+    pub fn all_around_neighbors<I: GridIndex>(
+        &self,
+        index: I,
+    ) -> Result<AllAroundNeighbor<'_, T>, GridError> {
+        let index = index.grid_index(self)?;
+        Ok(AllAroundNeighbor {
+            upleft: self.get_upleft(index),
+            up: self.get_up(index),
+            upright: self.get_upright(index),
+            left: self.get_left(index),
+            right: self.get_right(index),
+            downleft: self.get_downleft(index),
+            down: self.get_down(index),
+            downright: self.get_downright(index),
+        })
+    }
+
+    /// Returns the 3x3 neighborhood of a cell as a small grid, with the cell itself in the center and each neighbor in the direction it is in.  Neighbors
+    /// off the edge of the grid are `None`, unless the grid wraps.  Which neighbor is up follows the same rules as `get_up`.  The neighborhood has an
+    /// `Origin::Center` with y increasing upwards, so `(0, 0)` is the cell and `(0, 1)` is the up neighbor.
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec![0, 1, 2],
+    ///             vec![3, 4, 5],
+    ///             vec![6, 7, 8],
+    /// ];
+    /// let options = GridOptions::default().with_neighbor_ybased(false);
+    /// let grid = Grid::new(vec, Some(options)).expect("failed to import 2d vec");
+    /// let neighborhood = grid.neighborhood_3x3((1, 0)).expect("invalid coordinate");
+    /// assert_eq!(neighborhood.get((0, 0)), Some(&Some(&1)));
+    /// assert_eq!(neighborhood.get((0, 1)), Some(&None));
+    /// assert_eq!(neighborhood.get((1, -1)), Some(&Some(&5)));
+    /// ```
+    pub fn neighborhood_3x3<I: GridIndex>(&self, index: I) -> Result<Grid<Option<&T>>, GridError> {
+        let index = index.grid_index(self)?;
+        let neighbor = |direction| {
+            self.direction_idx(index, direction)
+                .ok()
+                .map(|i| &self.items[i])
+        };
+        let items = vec![
+            neighbor(Direction::UpLeft),
+            neighbor(Direction::Up),
+            neighbor(Direction::UpRight),
+            neighbor(Direction::Left),
+            Some(&self.items[index]),
+            neighbor(Direction::Right),
+            neighbor(Direction::DownLeft),
+            neighbor(Direction::Down),
+            neighbor(Direction::DownRight),
+        ];
+        let options = GridOptions::default()
+            .with_origin(Origin::Center)
+            .with_inverted_y(false);
+        Ok(Grid::create(items, 3, 3, Some(options)))
+    }
+
+    /// Counts the neighbors of a cell where `f` returns true.  Only the up, down, left and right neighbors are considered, unless `diagonal` is true.
+    /// Neighbors off the edge of the grid are not counted, unless the grid wraps.
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec![1, 1, 0],
+    ///             vec![0, 0, 1],
+    ///             vec![1, 0, 0],
+    /// ];
+    /// let grid = Grid::new(vec, None).expect("failed to import 2d vec");
+    /// assert_eq!(grid.neighbor_count((1, 1), false, |c| *c == 1).expect("invalid coordinate"), 2);
+    /// assert_eq!(grid.neighbor_count((1, 1), true, |c| *c == 1).expect("invalid coordinate"), 4);
+    /// ```
+    pub fn neighbor_count<I: GridIndex, F: Fn(&T) -> bool>(
+        &self,
+        index: I,
+        diagonal: bool,
+        f: F,
+    ) -> Result<usize, GridError> {
+        let index = index.grid_index(self)?;
+        Ok(self
+            .neighbor_indexes(index, diagonal)
+            .filter(|i| f(&self.items[*i]))
+            .count())
+    }
+
+    /// Counts the neighbors of a cell that are equal to `value`.  See `neighbor_count` for which neighbors are considered.
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec![1, 1, 0],
+    ///             vec![0, 0, 1],
+    ///             vec![1, 0, 0],
+    /// ];
+    /// let grid = Grid::new(vec, None).expect("failed to import 2d vec");
+    /// assert_eq!(grid.count_neighbors_eq((1, 1), &1, true).expect("invalid coordinate"), 4);
+    /// ```
+    pub fn count_neighbors_eq<I: GridIndex>(
+        &self,
+        index: I,
+        value: &T,
+        diagonal: bool,
+    ) -> Result<usize, GridError>
+    where
+        T: PartialEq,
+    {
+        self.neighbor_count(index, diagonal, |v| v == value)
+    }
+
+    /// Returns an iterator over the cells in a straight line from the cell at `index`, stepping by `dx` and `dy` each time as in `offset`.  The
+    /// starting cell is not included.  The iterator stops at the edge of the grid, unless the grid wraps, in which case it stops before returning to the
+    /// starting cell.
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec![0, 1, 2, 3],
+    ///             vec![4, 5, 6, 7],
+    ///             vec![8, 9, 10, 11],
+    /// ];
+    /// let grid = Grid::new(vec, None).expect("failed to import 2d vec");
+    /// let ray: Vec<_> = grid.ray((0, 1), 1, 0).expect("invalid coordinate").collect();
+    /// assert_eq!(ray, vec![&5, &6, &7]);
+    /// let ray: Vec<_> = grid.ray((3, 0), -1, 1).expect("invalid coordinate").collect();
+    /// assert_eq!(ray, vec![&6, &9]);
+    /// ```
+    pub fn ray<I: GridIndex>(
+        &self,
+        index: I,
+        dx: isize,
+        dy: isize,
+    ) -> Result<impl Iterator<Item = &T>, GridError> {
+        let start: (isize, isize) = Index::output(index.grid_index(self)?, self);
+        Ok(std::iter::successors(Some(start), move |&coord| {
+            self.offset(coord, dx, dy).filter(|&next| next != start)
+        })
+        .skip(1)
+        .map(move |coord| {
+            self.get(coord)
+                .expect("offset only returns coordinates in the grid")
+        }))
+    }
+
+    /// Returns every diagonal that runs from upper left to lower right in memory order, each as a `Vec` of the cells on it from top to bottom.  The
+    /// diagonals start with the single cell in the lower left corner and end with the single cell in the upper right, for `rows + columns - 1` in total.
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec![0, 1, 2],
+    ///             vec![3, 4, 5],
+    /// ];
+    /// let grid = Grid::new(vec, None).expect("failed to import 2d vec");
+    /// let diagonals: Vec<_> = grid.diagonals().collect();
+    /// assert_eq!(diagonals, vec![vec![&3], vec![&0, &4], vec![&1, &5], vec![&2]]);
+    /// ```
+    pub fn diagonals(&self) -> impl Iterator<Item = Vec<&T>> {
+        let (rows, cols) = (self.rows as isize, self.cols as isize);
+        (1 - rows..cols).map(move |offset| {
+            ((-offset).max(0)..rows.min(cols - offset))
+                .map(|row| &self.items[(row * cols + row + offset) as usize])
+                .collect()
+        })
+    }
+
+    /// Returns every diagonal that runs from upper right to lower left in memory order, each as a `Vec` of the cells on it from top to bottom.  The
+    /// diagonals start with the single cell in the upper left corner and end with the single cell in the lower right, for `rows + columns - 1` in total.
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec![0, 1, 2],
+    ///             vec![3, 4, 5],
+    /// ];
+    /// let grid = Grid::new(vec, None).expect("failed to import 2d vec");
+    /// let diagonals: Vec<_> = grid.anti_diagonals().collect();
+    /// assert_eq!(diagonals, vec![vec![&0], vec![&1, &3], vec![&2, &4], vec![&5]]);
+    /// ```
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = Vec<&T>> {
+        let (rows, cols) = (self.rows, self.cols);
+        (0..(rows + cols).saturating_sub(1)).map(move |sum| {
+            (sum.saturating_sub(cols - 1)..=sum.min(rows - 1))
+                .map(|row| &self.items[row * cols + sum - row])
+                .collect()
+        })
+    }
+
+    /// Returns an iterator along a diagonal, starting at the cell at `index` and stepping to the downright neighbor, or to the downleft neighbor when
+    /// `down_right` is false.  The iterator stops at the edge of the grid, unless the grid wraps, in which case it stops before returning to the starting cell.
+    /// The iterator is empty if `index` is outside the grid bounds.
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec![0, 1, 2],
+    ///             vec![3, 4, 5],
+    ///             vec![6, 7, 8],
+    /// ];
+    /// let gridoptions = GridOptions {
+    ///        neighbor_ybased: false,
+    ///        ..GridOptions::default()
+    /// };
+    /// let grid = Grid::new(vec, Some(gridoptions)).expect("failed to import 2d vec");
+    /// let diagonal: Vec<_> = grid.diagonal_iter((0, 0), true).collect();
+    /// assert_eq!(diagonal, vec![&0, &4, &8]);
+    /// let diagonal: Vec<_> = grid.diagonal_iter((2, 0), false).collect();
+    /// assert_eq!(diagonal, vec![&2, &4, &6]);
+    /// ```
+    pub fn diagonal_iter<I: GridIndex>(
+        &self,
+        index: I,
+        down_right: bool,
+    ) -> impl Iterator<Item = &T> {
+        let start = index.grid_index(self).ok();
+        std::iter::successors(start, move |&i| {
+            let next = if down_right {
+                self.downright_idx(i)
+            } else {
+                self.downleft_idx(i)
+            };
+            next.ok().filter(|&next| Some(next) != start)
+        })
+        .map(|i| &self.items[i])
+    }
+
+    /// Returns an iterator over the neighbors of a cell that are in the grid, skipping any that are off the edge.  Only the up, left, right and down
+    /// neighbors are yielded, in that order, unless `diagonal` is true, in which case they are followed by upleft, upright, downleft and downright.
+    /// Neighbors are wrapped around the grid when the `GridOptions` allow it.
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec![0, 1, 2],
+    ///             vec![3, 4, 5],
+    ///             vec![6, 7, 8],
+    /// ];
+    /// let grid = Grid::new(vec, None).expect("failed to import 2d vec");
+    /// let neighbors: Vec<_> = grid.neighbors_iter((0, 0), true).expect("invalid coordinate").collect();
+    /// assert_eq!(neighbors, vec![&3, &1, &4]);
+    /// ```
+    pub fn neighbors_iter<I: GridIndex>(
+        &self,
+        index: I,
+        diagonal: bool,
+    ) -> Result<impl Iterator<Item = &T>, GridError> {
+        let index = index.grid_index(self)?;
+        Ok(self
+            .neighbor_indexes(index, diagonal)
+            .map(|i| &self.items[i]))
+    }
+
+    /// Returns an iterator over the coordinates of the neighbors of a cell that are in the grid, in the same order as `neighbors_iter`.  Nothing is
+    /// allocated, so it is cheap enough to call for every cell visited in a breadth first search.  The iterator can be cloned to walk the neighbors again.
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec![0, 1, 2],
+    ///             vec![3, 4, 5],
+    ///             vec![6, 7, 8],
+    /// ];
+    /// let gridoptions = GridOptions::default().with_neighbor_ybased(false);
+    /// let grid = Grid::new(vec, Some(gridoptions)).expect("failed to import 2d vec");
+    /// let coords: Vec<_> = grid.neighbor_coords_iter((0, 0), false).expect("invalid coordinate").collect();
+    /// assert_eq!(coords, vec![(1, 0), (0, 1)]);
+    /// ```
+    pub fn neighbor_coords_iter<I: GridIndex>(
+        &self,
+        index: I,
+        diagonal: bool,
+    ) -> Result<impl Iterator<Item = (isize, isize)> + Clone + '_, GridError> {
+        let index = index.grid_index(self)?;
+        Ok(self
+            .neighbor_indexes(index, diagonal)
+            .map(|i| Index::output(i, self)))
+    }
+
+    /// Returns the neighbors of a cell that are in the grid, each with the `Direction` it is in and its coordinate.  Only the up, left, right and down
+    /// neighbors are returned, in that order, unless `diagonal` is true, in which case they are followed by upleft, upright, downleft and downright.
+    /// Neighbors are wrapped around the grid when the `GridOptions` allow it.
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec![0, 1, 2],
+    ///             vec![3, 4, 5],
+    /// ];
+    /// let gridoptions = GridOptions::default().with_neighbor_ybased(false);
+    /// let grid = Grid::new(vec, Some(gridoptions)).expect("failed to import 2d vec");
+    /// let neighbors = grid.labeled_neighbors((0, 0), false).expect("invalid coordinate");
+    /// assert_eq!(neighbors, vec![(Direction::Right, (1, 0), &1), (Direction::Down, (0, 1), &3)]);
+    /// ```
+    pub fn labeled_neighbors<I: GridIndex>(
+        &self,
+        index: I,
+        diagonal: bool,
+    ) -> Result<Vec<LabeledNeighbor<'_, T>>, GridError> {
+        let index = index.grid_index(self)?;
+        Ok(self
+            .labeled_neighbor_indexes(index, diagonal)
+            .map(|(direction, i)| (direction, Index::output(i, self), &self.items[i]))
+            .collect())
+    }
+
+    /// Returns all eight neighbors of a cell as an array, going clockwise starting from up, in the same order as `Direction::CLOCKWISE`:
+    /// up, upright, right, downright, down, downleft, left, upleft.  Neighbors off the edge of a non-wrapping grid are `None`.
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec![0, 1, 2],
+    ///             vec![3, 4, 5],
+    ///             vec![6, 7, 8],
+    /// ];
+    /// let gridoptions = GridOptions::default().with_neighbor_ybased(false);
+    /// let grid = Grid::new(vec, Some(gridoptions)).expect("failed to import 2d vec");
+    /// let weights = [1, 0, 1, 0, 1, 0, 1, 0];
+    /// let neighbors = grid.neighbors8((1, 1)).expect("invalid coordinate");
+    /// let total: i32 = neighbors.iter().zip(weights).map(|(n, w)| n.unwrap_or(&0) * w).sum();
+    /// assert_eq!(total, 1 + 5 + 7 + 3);
+    /// ```
+    pub fn neighbors8<I: GridIndex>(&self, index: I) -> Result<[Option<&T>; 8], GridError> {
+        let index = index.grid_index(self)?;
+        Ok(Direction::CLOCKWISE.map(|direction| {
+            self.direction_idx(index, direction)
+                .ok()
+                .map(|i| &self.items[i])
+        }))
+    }
+
+    /// Removes an entire row from the grid and returns the removed cells, from left to right.  `row` is the internal row number,
+    /// zero based from the top of the grid regardless of `Origin`.  Returns `IndexOutOfBounds` if `row` is not a row in the grid, and `InvalidSize` if
+    /// the grid only has one row left.
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec![0, 1, 2],
+    ///             vec![3, 4, 5],
+    ///             vec![6, 7, 8],
+    /// ];
+    /// let mut grid = Grid::new(vec, None).expect("failed to import 2d vec");
+    /// let removed = grid.remove_row(1).expect("invalid row");
+    /// assert_eq!(removed, vec![3, 4, 5]);
+    /// assert_eq!(grid.rows(), 2);
+    /// assert_eq!(grid.get((1, 1)), Some(&7));
+    /// ```
+    pub fn remove_row(&mut self, row: usize) -> Result<Vec<T>, GridError> {
+        if row >= self.rows {
+            return Err(out_of_bounds(self, 0, row as isize));
+        }
+        if self.rows == 1 {
+            return Err(GridError::InvalidSize);
+        }
+        let start = row * self.cols;
+        let removed = self.items.drain(start..start + self.cols).collect();
+        self.rows -= 1;
+        Ok(removed)
+    }
+
+    /// Removes an entire column from the grid and returns the removed cells, from top to bottom.  `column` is the internal column number,
+    /// zero based from the left of the grid regardless of `Origin`.  Returns `IndexOutOfBounds` if `column` is not a column in the grid, and `InvalidSize` if
+    /// the grid only has one column left.
+    pub fn remove_column(&mut self, column: usize) -> Result<Vec<T>, GridError> {
+        if column >= self.cols {
+            return Err(out_of_bounds(self, column as isize, 0));
+        }
+        if self.cols == 1 {
+            return Err(GridError::InvalidSize);
+        }
+        let cols = self.cols;
+        let mut removed = Vec::with_capacity(self.rows);
+        let mut kept = Vec::with_capacity(self.size() - self.rows);
+        for (i, item) in std::mem::take(&mut self.items).into_iter().enumerate() {
+            if i % cols == column {
+                removed.push(item);
+            } else {
+                kept.push(item);
+            }
+        }
+        self.items = kept;
+        self.cols -= 1;
+        Ok(removed)
+    }
+
+    /// Inserts a new row of `values` before the internal row number `row`, zero based from the top of the grid regardless of `Origin`.
+    /// Inserting at `row == self.rows()` appends the row to the bottom of the grid.  Returns `RowSizeMismatch` if the number of values
+    /// does not equal the number of columns, and `IndexOutOfBounds` if `row` is past the end of the grid.  The first row of an empty grid sets the
+    /// number of columns, and returns `InvalidSize` if it has no values.
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec![0, 1, 2],
+    ///             vec![6, 7, 8],
+    /// ];
+    /// let mut grid = Grid::new(vec, None).expect("failed to import 2d vec");
+    /// grid.insert_row(1, vec![3, 4, 5]).expect("invalid row");
+    /// assert_eq!(grid.rows(), 3);
+    /// assert_eq!(grid.get((1, 1)), Some(&4));
+    /// ```
+    pub fn insert_row(&mut self, row: usize, values: Vec<T>) -> Result<(), GridError> {
+        if self.is_empty() && row == 0 {
+            if values.is_empty() {
+                return Err(GridError::InvalidSize);
+            }
+            self.cols = values.len();
+            self.rows = 1;
+            self.items = values;
+            return Ok(());
+        }
+        if values.len() != self.cols {
+            return Err(GridError::RowSizeMismatch {
+                row,
+                expected: self.cols,
+                actual: values.len(),
+            });
+        }
+        if row > self.rows {
+            return Err(out_of_bounds(self, 0, row as isize));
+        }
+        let start = row * self.cols;
+        self.items.splice(start..start, values);
+        self.rows += 1;
+        Ok(())
+    }
+
+    /// Inserts a new column of `values` before the internal column number `column`, zero based from the left of the grid regardless of `Origin`.
+    /// Values are placed from top to bottom.  Inserting at `column == self.columns()` appends the column to the right side of the grid.
+    /// Returns `RowSizeMismatch` with `row` set to `column` if the number of values does not equal the number of rows, and `IndexOutOfBounds` if
+    /// `column` is past the end of the grid.  The first column of an empty grid sets the number of rows, and returns `InvalidSize` if it has no values.
+    pub fn insert_column(&mut self, column: usize, values: Vec<T>) -> Result<(), GridError> {
+        if self.is_empty() && column == 0 {
+            if values.is_empty() {
+                return Err(GridError::InvalidSize);
+            }
+            self.rows = values.len();
+            self.cols = 1;
+            self.items = values;
+            return Ok(());
+        }
+        if values.len() != self.rows {
+            return Err(GridError::RowSizeMismatch {
+                row: column,
+                expected: self.rows,
+                actual: values.len(),
+            });
+        }
+        if column > self.cols {
+            return Err(out_of_bounds(self, column as isize, 0));
+        }
+        let cols = self.cols;
+        let mut items = Vec::with_capacity(self.size() + self.rows);
+        let mut values = values.into_iter();
+        for (i, item) in std::mem::take(&mut self.items).into_iter().enumerate() {
+            if i % cols == column {
+                items.extend(values.next());
+            }
+            items.push(item);
+            if column == cols && i % cols == cols - 1 {
+                items.extend(values.next());
+            }
+        }
+        self.items = items;
+        self.cols += 1;
+        Ok(())
+    }
+
+    /// Keeps only the rows where `f` returns true, removing the rest.  `f` is called once for each row, from the top row to the bottom row, with the
+    /// cells of the row from left to right.  Returns `InvalidSize`, leaving the grid unchanged, if every row would be removed.
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec![0, 1, 2],
+    ///             vec![3, 4, 5],
+    ///             vec![6, 7, 8],
+    /// ];
+    /// let mut grid = Grid::new(vec, None).expect("failed to import 2d vec");
+    /// grid.retain_rows(|row| row.contains(&4)).expect("all rows removed");
+    /// assert_eq!(grid.to_2d_vec(), vec![vec![3, 4, 5]]);
+    /// ```
+    pub fn retain_rows<F: FnMut(&[T]) -> bool>(&mut self, mut f: F) -> Result<(), GridError> {
+        let keep: Vec<bool> = self.row_slices().map(&mut f).collect();
+        let kept_rows = keep.iter().filter(|k| **k).count();
+        if kept_rows == 0 {
+            return Err(GridError::InvalidSize);
+        }
+        let cols = self.cols;
+        let mut i = 0;
+        self.items.retain(|_| {
+            let kept = keep[i / cols];
+            i += 1;
+            kept
+        });
+        self.rows = kept_rows;
+        Ok(())
+    }
+
+    /// Keeps only the columns where `f` returns true, removing the rest.  `f` is called once for each column, from the left column to the right
+    /// column, with the cells of the column from top to bottom.  Returns `InvalidSize`, leaving the grid unchanged, if every column would be removed.
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec![0, 1, 2],
+    ///             vec![3, 4, 5],
+    ///             vec![6, 7, 8],
+    /// ];
+    /// let mut grid = Grid::new(vec, None).expect("failed to import 2d vec");
+    /// grid.retain_cols(|col| *col[0] != 1).expect("all columns removed");
+    /// assert_eq!(grid.to_2d_vec(), vec![vec![0, 2], vec![3, 5], vec![6, 8]]);
+    /// ```
+    pub fn retain_cols<F: FnMut(&[&T]) -> bool>(&mut self, mut f: F) -> Result<(), GridError> {
+        let keep: Vec<bool> = self
+            .cols_iter()
+            .map(|col| f(&col.collect::<Vec<_>>()))
+            .collect();
+        let kept_cols = keep.iter().filter(|k| **k).count();
+        if kept_cols == 0 {
+            return Err(GridError::InvalidSize);
+        }
+        let cols = self.cols;
+        let mut i = 0;
+        self.items.retain(|_| {
+            let kept = keep[i % cols];
+            i += 1;
+            kept
+        });
+        self.cols = kept_cols;
+        Ok(())
+    }
+
+    /// Swaps the contents of two whole rows.  `a` and `b` are internal row numbers, zero based from the top of the grid regardless of `Origin`.
+    /// Returns `IndexOutOfBounds` if either is not a row in the grid.
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec![0, 1, 2],
+    ///             vec![3, 4, 5],
+    ///             vec![6, 7, 8],
+    /// ];
+    /// let mut grid = Grid::new(vec, None).expect("failed to import 2d vec");
+    /// grid.swap_rows(0, 2).expect("invalid row");
+    /// assert_eq!(grid.to_2d_vec(), vec![vec![6, 7, 8], vec![3, 4, 5], vec![0, 1, 2]]);
+    /// ```
+    pub fn swap_rows(&mut self, a: usize, b: usize) -> Result<(), GridError> {
+        for row in [a, b] {
+            if row >= self.rows {
+                return Err(out_of_bounds(self, 0, row as isize));
+            }
+        }
+        for col in 0..self.cols {
+            self.items.swap(a * self.cols + col, b * self.cols + col);
+        }
+        Ok(())
+    }
+
+    /// Swaps the contents of two whole columns.  `a` and `b` are internal column numbers, zero based from the left of the grid regardless of `Origin`.
+    /// Returns `IndexOutOfBounds` if either is not a column in the grid.
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec![0, 1, 2],
+    ///             vec![3, 4, 5],
+    ///             vec![6, 7, 8],
+    /// ];
+    /// let mut grid = Grid::new(vec, None).expect("failed to import 2d vec");
+    /// grid.swap_cols(0, 1).expect("invalid column");
+    /// assert_eq!(grid.to_2d_vec(), vec![vec![1, 0, 2], vec![4, 3, 5], vec![7, 6, 8]]);
+    /// ```
+    pub fn swap_cols(&mut self, a: usize, b: usize) -> Result<(), GridError> {
+        for col in [a, b] {
+            if col >= self.cols {
+                return Err(out_of_bounds(self, col as isize, 0));
+            }
+        }
+        for row in 0..self.rows {
+            self.items.swap(row * self.cols + a, row * self.cols + b);
+        }
+        Ok(())
+    }
+
+    /// Reverses the order of the rows, so the top row becomes the bottom row, turning the grid upside down.  The `GridOptions` are unchanged, so
+    /// the value at a coordinate changes while the coordinates themselves stay put.
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec![0, 1, 2],
+    ///             vec![3, 4, 5],
+    /// ];
+    /// let mut grid = Grid::new(vec, None).expect("failed to import 2d vec");
+    /// grid.reverse_row_order();
+    /// assert_eq!(grid.to_2d_vec(), vec![vec![3, 4, 5], vec![0, 1, 2]]);
+    /// ```
+    pub fn reverse_row_order(&mut self) {
+        self.items.reverse();
+        self.reverse_col_order();
+    }
+
+    /// Reverses the order of the columns, so the left column becomes the right column, mirroring the grid.  The `GridOptions` are unchanged, so
+    /// the value at a coordinate changes while the coordinates themselves stay put.
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec![0, 1, 2],
+    ///             vec![3, 4, 5],
+    /// ];
+    /// let mut grid = Grid::new(vec, None).expect("failed to import 2d vec");
+    /// grid.reverse_col_order();
+    /// assert_eq!(grid.to_2d_vec(), vec![vec![2, 1, 0], vec![5, 4, 3]]);
+    /// ```
+    pub fn reverse_col_order(&mut self) {
+        for row in self.row_slices_mut() {
+            row.reverse();
+        }
+    }
+
+    /// Calls `f` with each row, from the first row in memory to the last, so a whole row can be changed at once.
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec![3, 1, 2],
+    ///             vec![4, 6, 5],
+    /// ];
+    /// let mut grid = Grid::new(vec, None).expect("failed to import 2d vec");
+    /// grid.map_rows(|row| row.sort());
+    /// assert_eq!(grid.to_2d_vec(), vec![vec![1, 2, 3], vec![4, 5, 6]]);
+    /// ```
+    pub fn map_rows<F: FnMut(&mut [T])>(&mut self, f: F) {
+        self.row_slices_mut().for_each(f);
+    }
+
+    /// Calls `f` with each column, from the first column in memory to the last, so a whole column can be changed at once.  The cells of a column are
+    /// not next to each other in memory, so each column is cloned into a temporary `Vec` that is written back after `f` returns.
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec![3, 1],
+    ///             vec![2, 6],
+    ///             vec![1, 5],
+    /// ];
+    /// let mut grid = Grid::new(vec, None).expect("failed to import 2d vec");
+    /// grid.map_cols(|col| col.sort());
+    /// assert_eq!(grid.to_2d_vec(), vec![vec![1, 1], vec![2, 5], vec![3, 6]]);
+    /// ```
+    pub fn map_cols<F: FnMut(&mut [T])>(&mut self, mut f: F)
+    where
+        T: Clone,
+    {
+        let mut column = Vec::with_capacity(self.rows);
+        for col in 0..self.cols {
+            column.clear();
+            column.extend(self.items.iter().skip(col).step_by(self.cols).cloned());
+            f(&mut column);
+            for (cell, value) in self
+                .items
+                .iter_mut()
+                .skip(col)
+                .step_by(self.cols)
+                .zip(column.drain(..))
+            {
+                *cell = value;
+            }
+        }
+    }
+
+    /// Rotates a square grid 90 degrees clockwise, swapping the cells in place, one ring of the grid at a time, rather than allocating a new `Vec`.
+    /// Returns `InvalidSize` if the number of rows and columns are not the same.  The `GridOptions` are unchanged.
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec![0, 1, 2],
+    ///             vec![3, 4, 5],
+    ///             vec![6, 7, 8],
+    /// ];
+    /// let mut grid = Grid::new(vec, None).expect("failed to import 2d vec");
+    /// grid.rotate_cw_in_place().expect("grid is not square");
+    /// assert_eq!(grid.to_2d_vec(), vec![vec![6, 3, 0], vec![7, 4, 1], vec![8, 5, 2]]);
+    /// ```
+    pub fn rotate_cw_in_place(&mut self) -> Result<(), GridError> {
+        if !self.is_square() {
+            return Err(GridError::InvalidSize);
+        }
+        let n = self.rows;
+        let at = |row: usize, col: usize| row * n + col;
+        for layer in 0..n / 2 {
+            let last = n - 1 - layer;
+            for i in layer..last {
+                let offset = i - layer;
+                let top = at(layer, i);
+                let right = at(i, last);
+                let bottom = at(last, last - offset);
+                let left = at(last - offset, layer);
+                self.items.swap(top, right);
+                self.items.swap(top, bottom);
+                self.items.swap(top, left);
+            }
+        }
+        Ok(())
+    }
+
+    /// Rotates the grid 180 degrees by reversing the cells in place, the same as `reverse_row_order` followed by `reverse_col_order`.  Unlike
+    /// `rotate_cw_in_place`, the grid does not need to be square.  The number of rows and columns, and the `GridOptions`, are unchanged.
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec![0, 1, 2],
+    ///             vec![3, 4, 5],
+    /// ];
+    /// let mut grid = Grid::new(vec, None).expect("failed to import 2d vec");
+    /// grid.rotate_180();
+    /// assert_eq!(grid.to_2d_vec(), vec![vec![5, 4, 3], vec![2, 1, 0]]);
+    /// ```
+    pub fn rotate_180(&mut self) {
+        self.items.reverse();
+    }
+
+    /// Moves every cell `dx` columns to the right and `dy` rows down, using internal positions, so a positive `dy` always moves towards the bottom
+    /// of the grid regardless of `Origin` or `inverted_y`.  Negative offsets move left and up.
+    ///
+    /// When `wrap` is true, cells shifted off one edge come back in on the opposite edge and `fill` is not used.  Otherwise, cells shifted off
+    /// the edge are dropped and the vacated cells are set to `fill`.
+    ///
+    /// Panics if `wrap` is false and `fill` is `None`, as there would be nothing to put in the vacated cells.
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec![0, 1, 2],
+    ///             vec![3, 4, 5],
+    /// ];
+    /// let mut grid = Grid::new(vec, None).expect("failed to import 2d vec");
+    /// grid.shift(1, 0, true, None);
+    /// assert_eq!(grid.to_2d_vec(), vec![vec![2, 0, 1], vec![5, 3, 4]]);
+    /// grid.shift(-1, 1, false, Some(9));
+    /// assert_eq!(grid.to_2d_vec(), vec![vec![9, 9, 9], vec![0, 1, 9]]);
+    /// ```
+    pub fn shift(&mut self, dx: isize, dy: isize, wrap: bool, fill: Option<T>)
+    where
+        T: Clone,
+    {
+        if self.is_empty() {
+            return;
+        }
+        let (cols, rows) = (self.cols as isize, self.rows as isize);
+        if wrap {
+            let dy = dy.rem_euclid(rows) as usize;
+            let dx = dx.rem_euclid(cols) as usize;
+            self.items.rotate_right(dy * self.cols);
+            for row in self.row_slices_mut() {
+                row.rotate_right(dx);
+            }
+            return;
+        }
+
+        let fill = fill.expect("a fill value is required when shifting without wrapping");
+        let mut shifted = vec![fill; self.size()];
+        for (i, item) in std::mem::take(&mut self.items).into_iter().enumerate() {
+            // An offset too large to add is shifted off the grid, the same as any other offset past the edge
+            let col = ((i % self.cols) as isize).checked_add(dx);
+            let row = ((i / self.cols) as isize).checked_add(dy);
+            if let (Some(col), Some(row)) = (col, row) {
+                if (0..cols).contains(&col) && (0..rows).contains(&row) {
+                    shifted[(row * cols + col) as usize] = item;
+                }
+            }
+        }
+        self.items = shifted;
+    }
+
+    /// Returns a new grid with a border of `fill` cells added around it, `left` columns on the left, `right` columns on the right, `top` rows on the top,
+    /// and `bottom` rows on the bottom.  The `GridOptions` are kept, so the coordinates of the original cells may change depending on the `Origin`.
+    /// Padding an empty grid along only one axis adds no cells, so the result is still empty.  Returns `ExcessiveSize` if the padded grid would be too
+    /// large.
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec![1, 2],
+    ///             vec![3, 4],
+    /// ];
+    /// let grid = Grid::new(vec, None).expect("failed to import 2d vec");
+    /// let padded = grid.pad(1, 0, 0, 1, 0).expect("padded grid is too large");
+    /// assert_eq!(padded.to_2d_vec(), vec![vec![0, 1, 2], vec![0, 3, 4], vec![0, 0, 0]]);
+    /// ```
+    pub fn pad(
+        &self,
+        left: usize,
+        right: usize,
+        top: usize,
+        bottom: usize,
+        fill: T,
+    ) -> Result<Grid<T>, GridError>
+    where
+        T: Clone,
+    {
+        let add = |size: usize, a: usize, b: usize| {
+            size.checked_add(a)
+                .and_then(|size| size.checked_add(b))
+                .ok_or(GridError::ExcessiveSize)
+        };
+        let cols = add(self.cols, left, right)?;
+        let rows = add(self.rows, top, bottom)?;
+        if cols == 0 || rows == 0 {
+            return Ok(Grid::create(Vec::new(), 0, 0, Some(self.options.clone())));
+        }
+        let total = row_col_length_check(rows, cols)?;
+        let mut items = Vec::with_capacity(total);
+        items.resize(top * cols, fill.clone());
+        for row in self.row_slices() {
+            items.resize(items.len() + left, fill.clone());
+            items.extend_from_slice(row);
+            items.resize(items.len() + right, fill.clone());
+        }
+        items.resize(total, fill);
+        Ok(Grid::create(items, rows, cols, Some(self.options.clone())))
+    }
+
+    /// Returns a new grid with `left` columns removed from the left, `right` columns removed from the right, `top` rows removed from the top, and
+    /// `bottom` rows removed from the bottom.  This is the inverse of `pad`.  Returns `InvalidSize` if no cells would be left.
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec![0, 1, 2],
+    ///             vec![3, 4, 5],
+    ///             vec![6, 7, 8],
+    /// ];
+    /// let grid = Grid::new(vec, None).expect("failed to import 2d vec");
+    /// let cropped = grid.crop(1, 0, 0, 1).expect("cropped everything");
+    /// assert_eq!(cropped.to_2d_vec(), vec![vec![1, 2], vec![4, 5]]);
+    /// ```
+    pub fn crop(
+        &self,
+        left: usize,
+        right: usize,
+        top: usize,
+        bottom: usize,
+    ) -> Result<Grid<T>, GridError>
+    where
+        T: Clone,
+    {
+        let cols = self
+            .cols
+            .checked_sub(left.saturating_add(right))
+            .filter(|cols| *cols > 0)
+            .ok_or(GridError::InvalidSize)?;
+        let rows = self
+            .rows
+            .checked_sub(top.saturating_add(bottom))
+            .filter(|rows| *rows > 0)
+            .ok_or(GridError::InvalidSize)?;
+        let items = self
+            .row_slices()
+            .skip(top)
+            .take(rows)
+            .flat_map(|row| row[left..left + cols].iter().cloned())
+            .collect();
+        Ok(Grid::create(items, rows, cols, Some(self.options.clone())))
+    }
+
+    /// Sets every cell in the `width` x `height` block whose upper left corner in memory is `top_left` to `value`, such as for drawing a room onto a
+    /// map.  Returns `IndexOutOfBounds` if any of the block is outside the grid, and the grid is left unchanged.
+    /// ```
+    /// use neighborgrid::*;
+    /// let mut grid = Grid::new(vec![vec!['.'; 4]; 3], None).expect("failed to import 2d vec");
+    /// grid.fill_rect((1, 1), 3, 2, '#').expect("rectangle does not fit");
+    /// assert_eq!(grid.render(|c| *c), "....\n.###\n.###");
+    /// assert!(grid.fill_rect((2, 0), 3, 1, '#').is_err());
+    /// ```
+    pub fn fill_rect<I: GridIndex>(
+        &mut self,
+        top_left: I,
+        width: usize,
+        height: usize,
+        value: T,
+    ) -> Result<(), GridError>
+    where
+        T: Clone,
+    {
+        let start = top_left.grid_index(self)?;
+        self.rect_check(start, width, height)?;
+        let col = start % self.cols;
+        for row in self
+            .items
+            .chunks_mut(self.cols)
+            .skip(start / self.cols)
+            .take(height)
+        {
+            row[col..col + width].fill(value.clone());
+        }
+        Ok(())
+    }
+
+    /// Copies every cell of `src` into this grid, with the upper left cell of `src` in memory placed at `at`, such as for stamping a tile onto a map.
+    /// Returns `IndexOutOfBounds` if `src` does not fit, and the grid is left unchanged.  The `GridOptions` of `src` are ignored, and an empty `src`
+    /// changes nothing.
+    /// ```
+    /// use neighborgrid::*;
+    /// let mut grid = Grid::new(vec![vec![0; 3]; 3], None).expect("failed to import 2d vec");
+    /// let tile = Grid::new(vec![vec![1, 2], vec![3, 4]], None).expect("failed to import 2d vec");
+    /// grid.blit(&tile, (1, 0)).expect("tile does not fit");
+    /// assert_eq!(grid.to_2d_vec(), vec![vec![0, 1, 2], vec![0, 3, 4], vec![0, 0, 0]]);
+    /// ```
+    pub fn blit<I: GridIndex>(&mut self, src: &Grid<T>, at: I) -> Result<(), GridError>
+    where
+        T: Clone,
+    {
+        let start = at.grid_index(self)?;
+        self.rect_check(start, src.cols, src.rows)?;
+        let col = start % self.cols;
+        for (row, src_row) in self
+            .items
+            .chunks_mut(self.cols)
+            .skip(start / self.cols)
+            .zip(src.row_slices())
+        {
+            row[col..col + src.cols].clone_from_slice(src_row);
+        }
+        Ok(())
+    }
+
+    /// Checks that a `width` x `height` block with its upper left corner in memory at `start` fits in the grid
+    fn rect_check(&self, start: usize, width: usize, height: usize) -> Result<(), GridError> {
+        let (col, row) = (start % self.cols, start / self.cols);
+        if col.saturating_add(width) > self.cols || row.saturating_add(height) > self.rows {
+            return Err(out_of_bounds(
+                self,
+                col.saturating_add(width.saturating_sub(1)) as isize,
+                row.saturating_add(height.saturating_sub(1)) as isize,
+            ));
+        }
+        Ok(())
+    }
+
+    /// The rows of the backing `Vec`.  Empty for a grid with no columns, rather than panicking like `chunks(0)`.
+    fn row_slices(&self) -> std::slice::Chunks<'_, T> {
+        self.items.chunks(self.cols.max(1))
+    }
+
+    /// Mutable version of `row_slices`
+    fn row_slices_mut(&mut self) -> std::slice::ChunksMut<'_, T> {
+        self.items.chunks_mut(self.cols.max(1))
+    }
+
+    /// Mutable references to the cells at the internal `indexes`, in the same order, with `None` left as `None`.  The indexes must be inside the grid.
+    /// Returns `DuplicateIndex` if any two are the same cell.
+    fn disjoint_cells_mut<const N: usize>(
+        &mut self,
+        indexes: [Option<usize>; N],
+    ) -> Result<[Option<&mut T>; N], GridError> {
+        // (internal index, position in the returned array), sorted so the backing slice can be split from front to back
+        let mut wanted: Vec<(usize, usize)> = indexes
+            .iter()
+            .enumerate()
+            .filter_map(|(slot, index)| Some(((*index)?, slot)))
+            .collect();
+        wanted.sort_unstable();
+        if wanted.windows(2).any(|pair| pair[0].0 == pair[1].0) {
+            return Err(GridError::DuplicateIndex);
+        }
+
+        let mut cells: [Option<&mut T>; N] = std::array::from_fn(|_| None);
+        let mut rest = self.items.as_mut_slice();
+        let mut consumed = 0;
+        for (index, slot) in wanted {
+            let (_, tail) = std::mem::take(&mut rest).split_at_mut(index - consumed);
+            let (item, tail) = tail
+                .split_first_mut()
+                .expect("Index already validated. This is not a public facing method");
+            cells[slot] = Some(item);
+            rest = tail;
+            consumed = index + 1;
+        }
+        Ok(cells)
+    }
+
+    /// The cells of the `width` x `height` block whose upper left cell in memory is at the internal (`left`, `top`) position, in row major order.
+    /// The block must be inside the grid.
+    fn block(&self, left: usize, top: usize, width: usize, height: usize) -> Vec<&T> {
+        (top..top + height)
+            .flat_map(|row| {
+                let start = row * self.cols + left;
+                &self.items[start..start + width]
+            })
+            .collect()
+    }
+
+    pub(crate) fn create(
+        items: Vec<T>,
+        rows: usize,
+        cols: usize,
+        options: Option<GridOptions>,
+    ) -> Grid<T> {
+        Grid {
+            items,
+            rows,
+            cols,
+            options: options.unwrap_or_default(),
+        }
+    }
+    #[inline]
+    pub(crate) fn origin(&self) -> Origin {
+        self.options.origin.clone()
+    }
+}
+
+impl<T> Grid<Option<T>> {
+    /// Returns a mutable reference to the value in the specified cell, first setting the cell to the result of `f` if it is `None`.  A cell that
+    /// already holds a value is left alone and `f` is not called.  Returns the `GridError` if the cell is outside the grid bounds, also without calling `f`.
+    /// ```
+    /// use neighborgrid::*;
+    /// let mut grid: Grid<Option<String>> = Grid::new((2, 2, None), None).expect("invalid size");
+    /// grid.get_or_insert_with((1, 0), || "entity".to_string()).expect("invalid coordinate").push('!');
+    /// assert_eq!(grid.get((1, 0)), Some(&Some("entity!".to_string())));
+    /// assert_eq!(grid.get_or_insert_with((1, 0), || unreachable!()).expect("invalid coordinate"), "entity!");
+    /// ```
+    pub fn get_or_insert_with<I: GridIndex, F: FnOnce() -> T>(
+        &mut self,
+        index: I,
+        f: F,
+    ) -> Result<&mut T, GridError> {
+        Ok(self.get_result_mut(index)?.get_or_insert_with(f))
+    }
+}
+
+pub(crate) fn row_number<T>(grid: &Grid<T>, index: usize) -> usize {
+    index / grid.cols
+}
+
+pub(crate) fn col_number<T>(grid: &Grid<T>, index: usize) -> usize {
+    index % grid.cols
+}
+
+pub(crate) fn row_start_index<T>(grid: &Grid<T>, index: usize) -> usize {
+    row_number(grid, index) * grid.cols
+}
+
+pub(crate) fn col_start_index<T>(grid: &Grid<T>, index: usize) -> usize {
+    col_number(grid, index)
+}
+
+pub(crate) fn ceiling(a: usize, b: usize) -> usize {
+    a.div_ceil(b)
+}
+
+fn gcd(mut a: usize, mut b: usize) -> usize {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+#[cfg(test)]
+mod grid_tests {
+
+    fn center_grid() -> Grid<i32> {
+        let vec = vec![
+            vec![0, 1, 2],
+            vec![3, 4, 5],
+            vec![6, 7, 8],
+            vec![9, 10, 11],
+            vec![12, 13, 14],
+        ];
+        let gridoptions = GridOptions {
+            origin: Origin::Center,
+            inverted_y: false,
+            ..GridOptions::default()
+        };
+        let grid = Grid::new(vec, Some(gridoptions));
+        grid.unwrap()
+    }
+
+    fn wrap_grid(wrap_x: bool, wrap_y: bool) -> Grid<i32> {
+        let vec = vec![
+            vec![0, 1, 2],
             vec![3, 4, 5],
             vec![6, 7, 8],
             vec![9, 10, 11],
@@ -838,240 +3367,3231 @@ mod grid_tests {
         let grid = Grid::new(vec, Some(gridoptions));
         grid.unwrap()
     }
-    use super::*;
-    #[test]
-    fn should_contain_large_size() -> Result<(), GridError> {
-        let vec = vec![vec![1; u16::MAX as usize]; 1000];
-        let grid = vec.into_grid()?;
-        assert_eq!(grid.rows, 1000);
-        assert_eq!(grid.cols, usize::from(u16::MAX));
+    use super::*;
+    use crate::index::Coordinates;
+    #[test]
+    fn should_hash_equal_grids_equally() {
+        use std::collections::HashSet;
+
+        let mut set = HashSet::new();
+        assert!(set.insert(center_grid()));
+        assert!(!set.insert(center_grid()));
+        assert_eq!(set.len(), 1);
+
+        // Same items, different options
+        assert!(set.insert(Grid::new(center_grid().to_2d_vec(), None).unwrap()));
+        let mut changed = center_grid();
+        *changed.get_mut((0, 0)).unwrap() = 100;
+        assert!(set.insert(changed));
+        assert_eq!(set.len(), 3);
+        assert!(set.contains(&center_grid()));
+    }
+
+    mod flat_index {
+        use super::*;
+
+        #[test]
+        fn should_round_trip_center_coordinates() {
+            let grid = center_grid();
+            for coord in [(0, 0), (-1, 2), (1, -2), (1, 0), (0, -1)] {
+                let flat = grid.flat_index(coord).unwrap();
+                assert_eq!(grid.coord_of(flat), Some(coord));
+                assert_eq!(grid.iter().nth(flat), grid.get(coord));
+            }
+            assert_eq!(grid.flat_index((0, 0)).unwrap(), 7);
+            assert_eq!(grid.flat_index(Coordinates::new(-1, 2)).unwrap(), 0);
+        }
+
+        #[test]
+        fn should_reject_out_of_range() {
+            let grid = center_grid();
+            assert!(matches!(
+                grid.flat_index((2, 0)),
+                Err(GridError::IndexOutOfBounds { x: 2, y: 0 })
+            ));
+            assert_eq!(grid.coord_of(14), Some((1, -2)));
+            assert_eq!(grid.coord_of(15), None);
+        }
+    }
+
+    mod offset {
+        use super::*;
+
+        #[test]
+        fn should_offset_in_bounds() {
+            let grid = wrap_grid(false, false);
+            assert_eq!(grid.offset((0, 0), 0, 0), Some((0, 0)));
+            assert_eq!(grid.offset((0, 0), 2, 4), Some((2, 4)));
+            assert_eq!(grid.offset((2, 4), -1, -3), Some((1, 1)));
+
+            let grid = center_grid();
+            assert_eq!(grid.offset((0, 0), -1, 2), Some((-1, 2)));
+            assert_eq!(grid.offset((-1, 2), 2, -4), Some((1, -2)));
+            assert_eq!(grid.offset(Coordinates::new(1, 1), 0, -1), Some((1, 0)));
+        }
+
+        #[test]
+        fn should_be_none_off_edge() {
+            let grid = wrap_grid(false, false);
+            assert_eq!(grid.offset((0, 0), -1, 0), None);
+            assert_eq!(grid.offset((0, 0), 0, -1), None);
+            assert_eq!(grid.offset((2, 4), 1, 0), None);
+            assert_eq!(grid.offset((2, 4), 0, 1), None);
+            assert_eq!(grid.offset((3, 0), 0, 0), None);
+            assert_eq!(grid.offset((0, 0), isize::MAX, 0), None);
+
+            let grid = center_grid();
+            assert_eq!(grid.offset((0, 0), 0, 3), None);
+            assert_eq!(grid.offset((0, 0), -2, 0), None);
+        }
+
+        #[test]
+        fn should_be_none_on_overflow() {
+            let grid = center_grid();
+            for (dx, dy) in [
+                (isize::MAX, 0),
+                (isize::MIN, 0),
+                (0, isize::MAX),
+                (0, isize::MIN),
+                (isize::MAX, isize::MIN),
+            ] {
+                assert_eq!(grid.offset((0, 0), dx, dy), None, "({}, {})", dx, dy);
+                assert_eq!(grid.offset((1, -2), dx, dy), None, "({}, {})", dx, dy);
+                assert_eq!(grid.ray((1, -2), dx, dy).unwrap().count(), 0);
+            }
+
+            let grid = wrap_grid(false, false);
+            assert_eq!(grid.offset((2, 4), isize::MAX, isize::MAX), None);
+            assert_eq!(grid.offset((0, 0), isize::MIN, isize::MIN), None);
+        }
+
+        #[test]
+        fn should_wrap_large_offsets() {
+            let grid = center_grid().with_options(
+                GridOptions::default()
+                    .with_origin(Origin::Center)
+                    .with_inverted_y(false)
+                    .with_wrap(true, true),
+            );
+            // isize::MAX is one more than a multiple of 3
+            assert_eq!(grid.offset((0, 0), isize::MAX, 0), Some((1, 0)));
+            assert_eq!(grid.offset((0, 0), 0, isize::MIN).map(|(x, _)| x), Some(0));
+            assert_eq!(grid.offset((0, 0), 3 * 1000, 5 * 1000), Some((0, 0)));
+            assert!(grid.ray((0, 0), isize::MAX, isize::MIN).unwrap().count() > 0);
+        }
+
+        #[test]
+        fn should_wrap_offset() {
+            let grid = wrap_grid(true, false);
+            assert_eq!(grid.offset((0, 0), -1, 0), Some((2, 0)));
+            assert_eq!(grid.offset((2, 1), 4, 1), Some((0, 2)));
+            assert_eq!(grid.offset((0, 0), 0, -1), None);
+
+            let grid = wrap_grid(true, true);
+            assert_eq!(grid.offset((0, 0), -1, -1), Some((2, 4)));
+            assert_eq!(grid.offset((1, 1), 3, 10), Some((1, 1)));
+        }
+    }
+
+    mod distance {
+        use super::*;
+
+        #[test]
+        fn should_measure_distance_without_wrap() {
+            let grid = wrap_grid(false, false);
+            assert_eq!(grid.manhattan_distance((0, 0), (2, 4)).unwrap(), 6);
+            assert_eq!(grid.chebyshev_distance((0, 0), (2, 4)).unwrap(), 4);
+            assert_eq!(grid.manhattan_distance((2, 4), (0, 0)).unwrap(), 6);
+            assert_eq!(grid.manhattan_distance((1, 2), (1, 2)).unwrap(), 0);
+            assert_eq!(grid.chebyshev_distance((0, 1), (1, 2)).unwrap(), 1);
+        }
+
+        #[test]
+        fn should_measure_shorter_distance_with_wrap() {
+            let grid = wrap_grid(true, true);
+            assert_eq!(grid.manhattan_distance((0, 0), (2, 4)).unwrap(), 2);
+            assert_eq!(grid.chebyshev_distance((0, 0), (2, 4)).unwrap(), 1);
+            // Going directly is still shorter
+            assert_eq!(grid.manhattan_distance((0, 0), (1, 2)).unwrap(), 3);
+
+            let grid = wrap_grid(false, true);
+            assert_eq!(grid.manhattan_distance((0, 0), (2, 4)).unwrap(), 3);
+            assert_eq!(grid.chebyshev_distance((0, 0), (2, 4)).unwrap(), 2);
+        }
+
+        #[test]
+        fn should_measure_center_origin_distance() {
+            let grid = center_grid();
+            assert_eq!(grid.manhattan_distance((-1, 2), (1, -2)).unwrap(), 6);
+            assert_eq!(grid.chebyshev_distance((-1, 2), (1, -2)).unwrap(), 4);
+        }
+
+        #[test]
+        fn should_err_on_invalid_index() {
+            let grid = center_grid();
+            assert!(matches!(
+                grid.manhattan_distance((0, 0), (2, 0)),
+                Err(GridError::IndexOutOfBounds { .. })
+            ));
+            assert!(matches!(
+                grid.chebyshev_distance((0, 3), (0, 0)),
+                Err(GridError::IndexOutOfBounds { .. })
+            ));
+        }
+    }
+
+    mod render {
+        use super::*;
+
+        #[test]
+        fn should_render_bool_grid() {
+            let vec = vec![
+                vec![false, true, false],
+                vec![false, false, true],
+                vec![true, true, true],
+            ];
+            let grid = Grid::new(vec, None).unwrap();
+            let text = grid.render(|alive| if *alive { '#' } else { '.' });
+            assert_eq!(text, ".#.\n..#\n###");
+
+            let parsed = Grid::from_char_lines(&text, None).unwrap();
+            assert_eq!(parsed.render(|c| *c), text);
+        }
+
+        #[test]
+        fn should_render_top_row_first() {
+            // The origin does not change the order rows are rendered in
+            let grid = center_grid();
+            let text = grid.render_with(|c| c.to_string(), ",");
+            assert_eq!(text, "0,1,2\n3,4,5\n6,7,8\n9,10,11\n12,13,14");
+
+            let grid = Grid::new(vec![vec![1, 2]], None).unwrap();
+            assert_eq!(grid.render_with(|c| c.to_string(), ""), "12");
+        }
+    }
+
+    #[test]
+    fn should_compare_pictures_across_origins() {
+        let vec = center_grid().to_2d_vec();
+        let upper_left = Grid::new(vec.clone(), None).unwrap();
+        let lower_left = Grid::new(
+            vec,
+            Some(
+                GridOptions::default()
+                    .with_origin(Origin::LowerLeft)
+                    .with_inverted_y(false),
+            ),
+        )
+        .unwrap();
+        assert!(upper_left.visually_eq(&lower_left));
+        assert!(lower_left.visually_eq(&center_grid()));
+        assert_ne!(upper_left, lower_left);
+
+        // Walking both grids from the visual top left gives the same cells
+        for row in 0..5 {
+            for col in 0..3 {
+                assert_eq!(upper_left.get((col, row)), lower_left.get((col, 4 - row)));
+            }
+        }
+
+        let mut flipped = lower_left.clone();
+        flipped.reverse_row_order();
+        assert!(!flipped.visually_eq(&upper_left));
+    }
+
+    #[test]
+    fn should_compare_layout_ignoring_options() {
+        let vec = center_grid().to_2d_vec();
+        let upper_left = Grid::new(vec.clone(), None).unwrap();
+        let center = center_grid();
+        assert!(upper_left.same_layout(&center));
+        assert!(center.same_layout(&upper_left));
+        assert_ne!(upper_left, center);
+
+        // Same items in a different shape
+        let reshaped = Grid::new_from_1d((0..15).collect(), 5, 3, None).unwrap();
+        assert_eq!(reshaped.items, upper_left.items);
+        assert!(!reshaped.same_layout(&upper_left));
+
+        let mut changed = center_grid();
+        *changed.get_mut((0, 0)).unwrap() = 100;
+        assert!(!changed.same_layout(&center));
+    }
+
+    mod new_from_1d {
+        use super::*;
+
+        #[test]
+        fn should_create_from_1d() {
+            let grid = Grid::new_from_1d((0..15).collect(), 3, 5, None).unwrap();
+            assert!(grid.same_layout(&center_grid()));
+        }
+
+        #[test]
+        fn should_err_on_size_mismatch() {
+            let grid = Grid::new_from_1d((0..14).collect(), 3, 5, None);
+            assert!(matches!(
+                grid,
+                Err(GridError::SizeMismatch {
+                    expected: 15,
+                    actual: 14
+                })
+            ));
+            assert_eq!(grid.unwrap_err().to_string(), "Expected 15 cells, found 14");
+
+            let grid = Grid::new_from_1d((0..16).collect(), 3, 5, None);
+            assert!(matches!(
+                grid,
+                Err(GridError::SizeMismatch {
+                    expected: 15,
+                    actual: 16
+                })
+            ));
+        }
+
+        #[test]
+        fn should_err_on_overflow() {
+            let grid = Grid::new_from_1d(vec![0], usize::MAX, 2, None);
+            assert!(matches!(grid, Err(GridError::ExcessiveSize)));
+            let grid = Grid::new_from_1d(
+                vec![0],
+                1 << (usize::BITS / 2),
+                1 << (usize::BITS / 2),
+                None,
+            );
+            assert!(matches!(grid, Err(GridError::ExcessiveSize)));
+        }
+
+        #[test]
+        fn should_err_on_zero_size() {
+            for (columns, rows) in [(0, 0), (0, 5), (3, 0)] {
+                let grid: Result<Grid<i32>, _> = Grid::new_from_1d(Vec::new(), columns, rows, None);
+                assert!(matches!(grid, Err(GridError::InvalidSize)));
+            }
+            let grid: Result<Grid<i32>, _> = Grid::from_1d_rows_cols(Vec::new(), 5, 0, None);
+            assert!(matches!(grid, Err(GridError::InvalidSize)));
+
+            let grid: Grid<i32> = Grid::empty(None);
+            assert_eq!(grid.col_iter(0).count(), 0);
+            assert_eq!(grid.col_iter((0, 0)).count(), 0);
+        }
+    }
+
+    mod from_1d_rows_cols {
+        use super::*;
+
+        #[test]
+        fn should_match_new_from_1d_with_swapped_args() {
+            let grid = Grid::from_1d_rows_cols((0..15).collect(), 5, 3, None).unwrap();
+            assert_eq!(grid.rows(), 5);
+            assert_eq!(grid.columns(), 3);
+            assert_eq!(
+                grid,
+                Grid::new_from_1d((0..15).collect(), 3, 5, None).unwrap()
+            );
+            assert_ne!(
+                grid,
+                Grid::new_from_1d((0..15).collect(), 5, 3, None).unwrap()
+            );
+        }
+
+        #[test]
+        fn should_err_on_size_mismatch() {
+            let grid = Grid::from_1d_rows_cols((0..14).collect(), 5, 3, None);
+            assert!(matches!(
+                grid,
+                Err(GridError::SizeMismatch {
+                    expected: 15,
+                    actual: 14
+                })
+            ));
+            let grid = Grid::from_1d_rows_cols(vec![0], 2, usize::MAX, None);
+            assert!(matches!(grid, Err(GridError::ExcessiveSize)));
+        }
+    }
+
+    mod dimensions {
+        use super::*;
+
+        #[test]
+        fn should_match_size() {
+            let grid = center_grid();
+            let dimensions = grid.dimensions();
+            assert_eq!(dimensions, Dimensions { rows: 5, cols: 3 });
+            assert_eq!(dimensions.total(), Some(grid.size()));
+
+            let grid = Grid::new_from_1d((0..8).collect(), 4, 2, None).unwrap();
+            assert_eq!(grid.dimensions().rows, grid.rows());
+            assert_eq!(grid.dimensions().cols, grid.columns());
+            assert_eq!(grid.dimensions().total(), Some(grid.size()));
+        }
+
+        #[test]
+        fn should_round_trip_through_dimensions() {
+            let grid = center_grid();
+            let copy = Grid::new(
+                (grid.iter().copied().collect(), grid.dimensions()),
+                Some(grid.options.clone()),
+            )
+            .unwrap();
+            assert_eq!(copy, grid);
+        }
+    }
+
+    mod shape {
+        use super::*;
+
+        #[test]
+        fn should_check_square() {
+            assert!(!center_grid().is_square());
+            let grid = Grid::new_from_1d((0..9).collect(), 3, 3, None).unwrap();
+            assert!(grid.is_square());
+            let grid = Grid::new_from_1d(vec![0], 1, 1, None).unwrap();
+            assert!(grid.is_square());
+        }
+
+        #[test]
+        fn should_reduce_aspect_ratio() {
+            assert_eq!(center_grid().aspect_ratio(), (3, 5));
+            let grid = Grid::new_from_1d((0..16).collect(), 4, 4, None).unwrap();
+            assert_eq!(grid.aspect_ratio(), (1, 1));
+            let grid = Grid::new_from_1d((0..32).collect(), 8, 4, None).unwrap();
+            assert_eq!(grid.aspect_ratio(), (2, 1));
+            let grid = Grid::new_from_1d((0..24).collect(), 4, 6, None).unwrap();
+            assert_eq!(grid.aspect_ratio(), (2, 3));
+            let grid: Grid<i32> = Grid::empty(None);
+            assert_eq!(grid.aspect_ratio(), (0, 0));
+        }
+    }
+
+    mod from_vec_with_cols {
+        use super::*;
+
+        #[test]
+        fn should_infer_rows() {
+            let grid = Grid::from_vec_with_cols((0..15).collect(), 3).unwrap();
+            assert_eq!(grid.rows(), 5);
+            assert_eq!(grid.columns(), 3);
+            assert!(grid.same_layout(&center_grid()));
+            assert_eq!(grid.options, GridOptions::default());
+
+            let grid = Grid::from_vec_with_cols((0..15).collect(), 15).unwrap();
+            assert_eq!(grid.rows(), 1);
+        }
+
+        #[test]
+        fn should_err_on_remainder() {
+            let grid = Grid::from_vec_with_cols((0..14).collect(), 3);
+            assert!(matches!(
+                grid,
+                Err(GridError::IncompleteRow {
+                    expected: 3,
+                    actual: 2
+                })
+            ));
+            let grid = Grid::from_vec_with_cols((0..2).collect(), 3);
+            assert!(matches!(
+                grid,
+                Err(GridError::IncompleteRow {
+                    expected: 3,
+                    actual: 2
+                })
+            ));
+            assert_eq!(
+                grid.unwrap_err().to_string(),
+                "Last row has 2 cells, expected 3"
+            );
+        }
+
+        #[test]
+        fn should_err_on_zero_size() {
+            let grid = Grid::from_vec_with_cols((0..3).collect(), 0);
+            assert!(matches!(grid, Err(GridError::InvalidSize)));
+            let grid: Result<Grid<i32>, _> = Grid::from_vec_with_cols(vec![], 3);
+            assert!(matches!(grid, Err(GridError::InvalidSize)));
+        }
+    }
+
+    #[test]
+    fn should_contain_large_size() -> Result<(), GridError> {
+        let vec = vec![vec![1; u16::MAX as usize]; 1000];
+        let grid = vec.into_grid()?;
+        assert_eq!(grid.rows, 1000);
+        assert_eq!(grid.cols, usize::from(u16::MAX));
+
+        let vec = vec![vec![1; 1000]; u16::MAX as usize];
+        let grid = vec.into_grid()?;
+        assert_eq!(grid.rows, u16::MAX as usize);
+        assert_eq!(grid.cols, 1000);
+
+        Ok(())
+    }
+
+    mod bounds {
+        use super::*;
+
+        #[test]
+        fn should_report_last_coordinate_as_max() {
+            let grid = wrap_grid(false, false);
+            assert_eq!((grid.min_x(), grid.max_x()), (0, 2));
+            assert_eq!((grid.min_y(), grid.max_y()), (-4, 0));
+
+            let grid = center_grid();
+            assert_eq!((grid.min_x(), grid.max_x()), (-1, 1));
+            assert_eq!((grid.min_y(), grid.max_y()), (-2, 2));
+
+            let gridoptions = GridOptions {
+                origin: Origin::LowerLeft,
+                ..GridOptions::default()
+            };
+            let grid = Grid::new_from_1d((0..15).collect(), 3, 5, Some(gridoptions)).unwrap();
+            assert_eq!((grid.min_x(), grid.max_x()), (0, 2));
+            assert_eq!((grid.min_y(), grid.max_y()), (0, 4));
+
+            let gridoptions = GridOptions {
+                origin: Origin::Center,
+                ..GridOptions::default()
+            };
+            let grid = Grid::new_from_1d((0..16).collect(), 4, 4, Some(gridoptions)).unwrap();
+            assert_eq!((grid.min_x(), grid.max_x()), (-2, 1));
+            assert_eq!((grid.min_y(), grid.max_y()), (-1, 2));
+        }
+
+        #[test]
+        fn should_not_get_one_past_the_edge() {
+            let grid = wrap_grid(false, false);
+            assert_eq!(grid.get((2, 0)), Some(&2));
+            assert_eq!(grid.get((3, 0)), None);
+            assert_eq!(grid.get((0, 4)), Some(&12));
+            assert_eq!(grid.get((0, 5)), None);
+
+            let grid = center_grid();
+            assert_eq!(grid.get((1, 2)), Some(&2));
+            assert_eq!(grid.get((2, 0)), None);
+            assert_eq!(grid.get((0, 3)), None);
+            assert_eq!(grid.get((0, -3)), None);
+        }
+    }
+
+    mod options {
+        use super::*;
+
+        #[test]
+        fn should_build_options() {
+            let built = GridOptions::default()
+                .with_origin(Origin::Center)
+                .with_inverted_y(false)
+                .with_neighbor_ybased(false)
+                .with_wrap(true, false);
+            let literal = GridOptions {
+                origin: Origin::Center,
+                inverted_y: false,
+                neighbor_ybased: false,
+                wrap_x: true,
+                wrap_y: false,
+            };
+            assert_eq!(built, literal);
+
+            let built = GridOptions::default().with_wrap(false, true);
+            let spread = GridOptions {
+                wrap_y: true,
+                ..GridOptions::default()
+            };
+            assert_eq!(built, spread);
+            assert_eq!(
+                GridOptions::default().with_origin(Origin::UpperLeft),
+                GridOptions::default()
+            );
+        }
+
+        #[test]
+        fn should_report_effective_up_direction() {
+            let vec = center_grid().to_2d_vec();
+            for (inverted_y, neighbor_ybased, positive, visually_above) in [
+                (false, false, true, true),
+                (false, true, true, true),
+                (true, false, false, true),
+                (true, true, true, false),
+            ] {
+                let gridoptions = GridOptions::default()
+                    .with_origin(Origin::Center)
+                    .with_inverted_y(inverted_y)
+                    .with_neighbor_ybased(neighbor_ybased);
+                assert_eq!(gridoptions.up_is_positive_y(), positive);
+
+                let grid = Grid::new(vec.clone(), Some(gridoptions)).unwrap();
+                let expected_y = if positive { 1 } else { -1 };
+                assert_eq!(grid.get_up((0, 0)), grid.get((0, expected_y)));
+                assert_eq!(grid.get_down((0, 0)), grid.get((0, -expected_y)));
+                let expected = if visually_above { &4 } else { &10 };
+                assert_eq!(grid.get_up((0, 0)), Some(expected));
+            }
+        }
+
+        #[test]
+        fn should_use_built_options() {
+            let gridoptions = GridOptions::default()
+                .with_origin(Origin::Center)
+                .with_inverted_y(false);
+            let vec = center_grid().to_2d_vec();
+            assert_eq!(Grid::new(vec, Some(gridoptions)).unwrap(), center_grid());
+        }
+    }
+
+    mod set_options {
+        use super::*;
+
+        #[test]
+        fn should_readdress_cells_with_new_options() {
+            let grid = wrap_grid(false, false);
+            assert_eq!(grid.get((0, 0)), Some(&0));
+            assert_eq!(<(isize, isize)>::output(0, &grid), (0, 0));
+
+            let grid = grid.with_options(center_grid().options);
+            assert_eq!(grid, center_grid());
+            assert_eq!(grid.get((-1, 2)), Some(&0));
+            assert_eq!(<(isize, isize)>::output(0, &grid), (-1, 2));
+
+            let mut grid = grid;
+            grid.set_options(
+                GridOptions::default()
+                    .with_origin(Origin::LowerLeft)
+                    .with_inverted_y(false),
+            );
+            assert_eq!(grid.get((0, 4)), Some(&0));
+            assert_eq!(<(isize, isize)>::output(0, &grid), (0, 4));
+            assert_eq!(grid.items, center_grid().items);
+        }
+    }
+
+    mod getters {
+        use super::*;
+
+        #[test]
+        fn should_get_item() {
+            let grid = center_grid();
+            assert_eq!(grid.get((0, 0)).unwrap(), &7i32);
+            assert_eq!(grid.get((-1, 1)).unwrap(), &3i32);
+            assert_eq!(grid.get(1).unwrap(), &1i32);
+            assert_eq!(grid.get((-2, 0)), None);
+        }
+
+        #[test]
+        fn should_not_be_empty() {
+            assert!(!center_grid().is_empty());
+            assert!(!Grid::new(vec![vec![0]], None).unwrap().is_empty());
+
+            let mut grid = wrap_grid(false, false);
+            while grid.rows() > 1 {
+                grid.remove_row(0).unwrap();
+            }
+            assert!(!grid.is_empty());
+            assert!(matches!(grid.remove_row(0), Err(GridError::InvalidSize)));
+            assert!(!grid.is_empty());
+        }
+
+        #[test]
+        fn should_wrap_far_outside_grid() {
+            let grid = center_grid();
+            for x in -1..=1 {
+                for y in -2..=2 {
+                    let expected = grid.get((x, y)).unwrap();
+                    assert_eq!(grid.wrapping_get((x, y)), expected);
+                    assert_eq!(grid.wrapping_get((x + 3, y)), expected);
+                    assert_eq!(grid.wrapping_get((x - 30, y + 50)), expected);
+                    assert_eq!(
+                        grid.wrapping_get(Coordinates {
+                            x: x + 300,
+                            y: y - 5
+                        }),
+                        expected
+                    );
+                }
+            }
+            // One past the right edge is the left edge, one past the top is the bottom
+            assert_eq!(grid.wrapping_get((2, 0)), &6);
+            assert_eq!(grid.wrapping_get((0, 3)), &13);
+            assert_eq!(grid.wrapping_get(15), &0);
+            assert_eq!(grid.wrapping_get(49), &4);
+        }
+
+        #[test]
+        fn should_wrap_regardless_of_options() {
+            let grid = wrap_grid(false, false);
+            assert_eq!(grid.get((3, 0)), None);
+            assert_eq!(grid.wrapping_get((3, 0)), &0);
+            assert_eq!(grid.wrapping_get((-1, -1)), &14);
+            assert_eq!(grid.wrapping_get((7, 12)), &7);
+        }
+
+        #[test]
+        fn should_wrap_extreme_coordinates() {
+            let extremes = [
+                isize::MIN,
+                isize::MIN + 1,
+                -1,
+                0,
+                1,
+                isize::MAX - 1,
+                isize::MAX,
+            ];
+            // Reduces `v` into the `len` values starting at `min`, without overflowing
+            let reduce = |v: isize, min: isize, len: isize| {
+                ((v as i128 - min as i128).rem_euclid(len as i128) + min as i128) as isize
+            };
+            for grid in [center_grid(), wrap_grid(false, false)] {
+                for &x in &extremes {
+                    for &y in &extremes {
+                        let x_in = reduce(x, grid.min_x(), 3);
+                        let (min_y, max_y) = if grid.options.inverted_y {
+                            (-grid.max_y(), -grid.min_y())
+                        } else {
+                            (grid.min_y(), grid.max_y())
+                        };
+                        assert_eq!(max_y - min_y, 4);
+                        let y_in = reduce(y, min_y, 5);
+                        assert_eq!(
+                            grid.wrapping_get((x, y)),
+                            grid.get((x_in, y_in)).unwrap(),
+                            "({}, {})",
+                            x,
+                            y
+                        );
+                    }
+                }
+            }
+            assert_eq!(center_grid().wrapping_get(usize::MAX), &0);
+        }
+
+        #[test]
+        fn should_get_whole_row_and_column() {
+            let grid = center_grid();
+            assert_eq!(grid.get_row(0), Some(&[0, 1, 2][..]));
+            assert_eq!(grid.get_row(4), Some(&[12, 13, 14][..]));
+            assert_eq!(grid.get_row(5), None);
+
+            assert_eq!(grid.get_col(0), Some(vec![&0, &3, &6, &9, &12]));
+            assert_eq!(grid.get_col(2), Some(vec![&2, &5, &8, &11, &14]));
+            assert_eq!(grid.get_col(3), None);
+        }
+
+        #[test]
+        fn should_get_cardinal_block_mut() {
+            let mut grid = wrap_grid(false, false);
+            let block = grid.cardinal_block_mut((1, 2)).unwrap();
+            let values: Vec<_> = block.iter().map(|c| c.as_deref().copied()).collect();
+            assert_eq!(values, vec![Some(7), Some(4), Some(10), Some(6), Some(8)]);
+            for (i, cell) in block.into_iter().enumerate() {
+                *cell.unwrap() = 100 + i as i32;
+            }
+            assert_eq!(grid.get((1, 2)), Some(&100));
+            assert_eq!(grid.get((1, 1)), Some(&101));
+            assert_eq!(grid.get((1, 3)), Some(&102));
+            assert_eq!(grid.get((0, 2)), Some(&103));
+            assert_eq!(grid.get((2, 2)), Some(&104));
+        }
+
+        #[test]
+        fn should_get_cardinal_block_mut_at_edge() {
+            let mut grid = wrap_grid(false, false);
+            let [center, up, down, left, right] = grid.cardinal_block_mut((0, 0)).unwrap();
+            assert!(up.is_none());
+            assert!(left.is_none());
+            *center.unwrap() = -1;
+            *down.unwrap() = -2;
+            *right.unwrap() = -3;
+            assert_eq!(grid.get_row(0), Some(&[-1, -3, 2][..]));
+            assert_eq!(grid.get_row(1), Some(&[-2, 4, 5][..]));
+
+            let mut grid = wrap_grid(true, true);
+            let block = grid.cardinal_block_mut((0, 0)).unwrap();
+            let values: Vec<_> = block.iter().map(|c| c.as_deref().copied()).collect();
+            assert_eq!(values, vec![Some(0), Some(12), Some(3), Some(2), Some(1)]);
+        }
+
+        #[test]
+        fn should_err_on_overlapping_cardinal_block() {
+            let gridoptions = GridOptions::default().with_wrap(true, false);
+            let mut grid = Grid::new(vec![vec![0, 1], vec![2, 3]], Some(gridoptions)).unwrap();
+            // Left and right both wrap to the same cell
+            assert!(matches!(
+                grid.cardinal_block_mut((0, 0)),
+                Err(GridError::DuplicateIndex)
+            ));
+            assert!(matches!(
+                grid.cardinal_block_mut((0, 2)),
+                Err(GridError::IndexOutOfBounds { .. })
+            ));
+        }
+
+        #[test]
+        fn should_take_cell() {
+            let mut grid = center_grid().try_map(|v| Ok::<_, ()>(Some(*v))).unwrap();
+            assert_eq!(grid.take((0, 0)).unwrap(), Some(7));
+            assert_eq!(grid.get((0, 0)), Some(&None));
+            assert_eq!(grid.take((0, 0)).unwrap(), None);
+            assert_eq!(grid.iter().filter(|c| c.is_none()).count(), 1);
+            assert!(matches!(
+                grid.take((2, 0)),
+                Err(GridError::IndexOutOfBounds { x: 2, y: 0 })
+            ));
+        }
+
+        #[test]
+        fn should_get_or_default() {
+            let grid = center_grid();
+            assert_eq!(grid.get_or((0, 0), &-1), &7);
+            assert_eq!(grid.get_or((-1, 2), &-1), &0);
+            assert_eq!(grid.get_or((2, 0), &-1), &-1);
+            assert_eq!(grid.get_or((0, -3), &-1), &-1);
+            assert_eq!(grid.get_or(15, &-1), &-1);
+        }
+
+        #[test]
+        fn should_get_or_insert_with() {
+            let mut grid = center_grid().try_map(|v| Ok::<_, ()>(Some(*v))).unwrap();
+            grid.take((0, 0)).unwrap();
+            *grid.get_or_insert_with((0, 0), || 70).unwrap() += 1;
+            assert_eq!(grid.get((0, 0)), Some(&Some(71)));
+
+            let mut calls = 0;
+            let value = grid
+                .get_or_insert_with((1, 1), || {
+                    calls += 1;
+                    0
+                })
+                .unwrap();
+            assert_eq!(*value, 5);
+            assert!(grid.get_or_insert_with((2, 0), || 0).is_err());
+            assert_eq!(calls, 0);
+            assert!(grid.iter().all(|c| c.is_some()));
+        }
+
+        #[test]
+        fn should_set_default() {
+            let mut grid = center_grid();
+            grid.set_default((1, -2)).unwrap();
+            assert_eq!(grid.get((1, -2)), Some(&0));
+            assert!(grid.set_default((1, -3)).is_err());
+            assert_eq!(grid.iter().filter(|v| **v == 0).count(), 2);
+        }
+
+        #[test]
+        fn should_get_result() {
+            let mut grid = center_grid();
+            assert_eq!(grid.get_result((0, 0)).unwrap(), &7);
+            assert_eq!(grid.get_result(14).unwrap(), &14);
+            assert!(matches!(
+                grid.get_result((-2, 0)),
+                Err(GridError::IndexOutOfBounds { x: -2, y: 0 })
+            ));
+            assert!(matches!(
+                grid.get_result(15),
+                Err(GridError::IndexOutOfBounds { .. })
+            ));
+
+            *grid.get_result_mut((-1, 1)).unwrap() = 30;
+            assert_eq!(grid.get((-1, 1)), Some(&30));
+            assert!(matches!(
+                grid.get_result_mut((0, 3)),
+                Err(GridError::IndexOutOfBounds { x: 0, y: 3 })
+            ));
+        }
+
+        #[test]
+        fn should_contain_coordinates() {
+            let grid = center_grid();
+            for x in -1..=1 {
+                for y in -2..=2 {
+                    assert!(grid.contains((x, y)), "({}, {})", x, y);
+                }
+            }
+            assert!(!grid.contains((-2, 0)));
+            assert!(!grid.contains((2, 0)));
+            assert!(!grid.contains((0, 3)));
+            assert!(!grid.contains((0, -3)));
+            assert!(grid.contains(14));
+            assert!(!grid.contains(15));
+
+            let grid = wrap_grid(true, true);
+            for x in 0..=2 {
+                for y in 0..=4 {
+                    assert!(grid.contains((x, y)), "({}, {})", x, y);
+                }
+            }
+            assert!(!grid.contains((-1, 0)));
+            assert!(!grid.contains((3, 0)));
+            assert!(!grid.contains((0, -1)));
+            assert!(!grid.contains((0, 5)));
+            assert!(grid.contains(Coordinates { x: 2, y: 4 }));
+            assert!(!grid.contains(Coordinates { x: 2, y: 5 }));
+
+            let gridoptions = GridOptions {
+                origin: Origin::Center,
+                inverted_y: false,
+                ..GridOptions::default()
+            };
+            let grid = Grid::new_from_1d((0..16).collect(), 4, 4, Some(gridoptions)).unwrap();
+            assert!(grid.contains((-2, 2)));
+            assert!(grid.contains((1, -1)));
+            assert!(!grid.contains((2, 0)));
+            assert!(!grid.contains((0, -2)));
+            assert!(!grid.contains((0, 3)));
+        }
+
+        #[test]
+        fn should_get_mut_item() {
+            let mut grid = center_grid();
+            let v = grid.get_mut((0, 0)).unwrap();
+            assert_eq!(*v, 7i32);
+            *v = 12i32;
+            assert_eq!(*v, 12i32);
+            let v = grid.get((0, 0)).unwrap();
+            assert_eq!(*v, 12i32);
+        }
+
+        #[test]
+        fn should_get_pair_mut() {
+            let mut grid = center_grid();
+            let (a, b) = grid.get_pair_mut((0, 0), (-1, 2)).unwrap();
+            assert_eq!((*a, *b), (7, 0));
+            std::mem::swap(a, b);
+            assert_eq!(grid.get((0, 0)), Some(&0));
+            assert_eq!(grid.get((-1, 2)), Some(&7));
+
+            let (a, b) = grid.get_pair_mut(14, 13).unwrap();
+            assert_eq!((*a, *b), (14, 13));
+        }
+
+        #[test]
+        fn should_get_disjoint_mut() {
+            let mut grid = center_grid();
+            let cells = grid.get_disjoint_mut([(1, -2), (0, 0), (-1, 2)]).unwrap();
+            assert_eq!(cells.map(|v| *v), [14, 7, 0]);
+
+            let [a, b, c] = grid.get_disjoint_mut([0, 1, 2]).unwrap();
+            *a = *b + *c;
+            assert_eq!(grid.get(0), Some(&3));
+        }
+
+        #[test]
+        fn should_err_on_duplicate_or_invalid_index() {
+            let mut grid = center_grid();
+            assert!(matches!(
+                grid.get_pair_mut((0, 0), (0, 0)),
+                Err(GridError::DuplicateIndex)
+            ));
+            assert!(matches!(
+                grid.get_disjoint_mut([(0, 0), (1, 1), (0, 0)]),
+                Err(GridError::DuplicateIndex)
+            ));
+            assert!(matches!(
+                grid.get_pair_mut((0, 0), (2, 0)),
+                Err(GridError::IndexOutOfBounds { .. })
+            ));
+        }
+
+        #[test]
+        fn should_swap_cells() {
+            let mut grid = center_grid();
+            grid.swap((0, 0), (1, -2)).unwrap();
+            assert_eq!(grid.get((0, 0)), Some(&14));
+            assert_eq!(grid.get((1, -2)), Some(&7));
+            assert!(matches!(
+                grid.swap((0, 0), (2, 0)),
+                Err(GridError::IndexOutOfBounds { .. })
+            ));
+        }
+
+        #[test]
+        fn should_err_swapping_cell_with_itself() {
+            let mut grid = center_grid();
+            assert!(matches!(
+                grid.swap((0, 0), (0, 0)),
+                Err(GridError::DuplicateIndex)
+            ));
+            assert!(matches!(grid.swap(3, 3), Err(GridError::DuplicateIndex)));
+            assert_eq!(grid, center_grid());
+        }
+
+        #[test]
+        fn should_get_up() {
+            let grid = center_grid();
+            assert_eq!(grid.get_up((0, 0)), Some(&4i32));
+            assert_eq!(grid.get_up((-1, 1)), Some(&0i32));
+            assert_eq!(grid.get_up(1), None);
+            assert_eq!(grid.get_up((-2, 0)), None);
+        }
+
+        #[test]
+        fn should_get_down() {
+            let grid = center_grid();
+            assert_eq!(grid.get_down((0, 0)), Some(&10i32));
+            assert_eq!(grid.get_down((-1, 1)), Some(&6i32));
+            assert_eq!(grid.get_down(12), None);
+            assert_eq!(grid.get_down((-2, 0)), None);
+        }
+
+        #[test]
+        fn should_get_left() {
+            let grid = center_grid();
+            assert_eq!(grid.get_left((0, 0)), Some(&6i32));
+            assert_eq!(grid.get_left((1, 1)), Some(&4i32));
+            assert_eq!(grid.get_left(12), None);
+            assert_eq!(grid.get_left((-2, 0)), None);
+        }
+
+        #[test]
+        fn should_get_right() {
+            let grid = center_grid();
+            assert_eq!(grid.get_right((0, 0)), Some(&8i32));
+            assert_eq!(grid.get_right((-1, -1)), Some(&10i32));
+            assert_eq!(grid.get_right(11), None);
+            assert_eq!(grid.get_right((-2, 0)), None);
+        }
+
+        #[test]
+        fn should_get_up_wrap() {
+            let grid = wrap_grid(false, true);
+            assert_eq!(grid.get_up((0, 1)), Some(&0i32));
+            assert_eq!(grid.get_up((0, 0)), Some(&12i32));
+            assert_eq!(grid.get_up((0, 2)), Some(&3i32));
+        }
+
+        #[test]
+        fn should_get_down_wrap() {
+            let grid = wrap_grid(false, true);
+            assert_eq!(grid.get_down((0, 3)), Some(&12i32));
+            assert_eq!(grid.get_down((0, 4)), Some(&0i32));
+            assert_eq!(grid.get_down((0, 0)), Some(&3i32));
+        }
+
+        #[test]
+        fn should_get_left_wrap() {
+            let grid = wrap_grid(true, false);
+            assert_eq!(grid.get_left((1, 0)), Some(&0i32));
+            assert_eq!(grid.get_left((0, 0)), Some(&2i32));
+            assert_eq!(grid.get_left((2, 0)), Some(&1i32));
+        }
+
+        #[test]
+        fn should_get_right_wrap() {
+            let grid = wrap_grid(true, false);
+            assert_eq!(grid.get_right((1, 0)), Some(&2i32));
+            assert_eq!(grid.get_right((2, 0)), Some(&0i32));
+            assert_eq!(grid.get_right((0, 0)), Some(&1i32));
+        }
+        #[test]
+        fn basic_quadrant() {
+            let vec = vec![vec![0, 1], vec![2, 3]];
+
+            let grid = Grid::new(vec, None).unwrap();
+            assert_eq!(grid.nrant((0, 0), 1).unwrap(), 0);
+            assert_eq!(grid.nrant((1, 0), 1).unwrap(), 0);
+            assert_eq!(grid.nrant((0, 1), 1).unwrap(), 0);
+            assert_eq!(grid.nrant((1, 1), 1).unwrap(), 0);
+
+            assert_eq!(grid.nrant((0, 0), 2).unwrap(), 0);
+            assert_eq!(grid.nrant((1, 0), 2).unwrap(), 1);
+            assert_eq!(grid.nrant((0, 1), 2).unwrap(), 2);
+            assert_eq!(grid.nrant((1, 1), 2).unwrap(), 3);
+        }
+
+        #[test]
+        fn uneven_quadrant() {
+            let vec = vec![vec![0, 1, 2], vec![3, 4, 5]];
+
+            let grid = Grid::new(vec, None).unwrap();
+
+            assert_eq!(grid.nrant((0, 0), 2).unwrap(), 0);
+            assert_eq!(grid.nrant((1, 0), 2).unwrap(), 0);
+            assert_eq!(grid.nrant((2, 0), 2).unwrap(), 1);
+            assert_eq!(grid.nrant((0, 1), 2).unwrap(), 2);
+            assert_eq!(grid.nrant((1, 1), 2).unwrap(), 2);
+            assert_eq!(grid.nrant((2, 1), 2).unwrap(), 3);
+        }
+
+        #[test]
+        fn nrant_start() {
+            let vec = vec![vec![0, 1], vec![2, 3]];
+
+            let grid = Grid::new(vec, None).unwrap();
+            assert_eq!(grid.nrant_start(0, 1), 0);
+            assert_eq!(grid.nrant_start(1, 1), 0);
+            assert_eq!(grid.nrant_start(2, 1), 0);
+            assert_eq!(grid.nrant_start(3, 1), 0);
+
+            assert_eq!(grid.nrant_start(0, 2), 0);
+            assert_eq!(grid.nrant_start(1, 2), 1);
+            assert_eq!(grid.nrant_start(2, 2), 2);
+            assert_eq!(grid.nrant_start(3, 2), 3);
+        }
+
+        #[test]
+        fn uneven_quadrant_start() {
+            let vec = vec![vec![0, 1, 2], vec![3, 4, 5]];
+
+            let grid = Grid::new(vec, None).unwrap();
+
+            assert_eq!(grid.nrant_start(0, 2), 0);
+            assert_eq!(grid.nrant_start(1, 2), 0);
+            assert_eq!(grid.nrant_start(2, 2), 2);
+            assert_eq!(grid.nrant_start(3, 2), 3);
+            assert_eq!(grid.nrant_start(4, 2), 3);
+            assert_eq!(grid.nrant_start(5, 2), 5);
+        }
+
+        #[test]
+        fn sudoku_nrant_coords() {
+            let grid = Grid::new_from_1d((1..=81).collect(), 9, 9, None).unwrap();
+            let coords = grid.nrant_coords(3, (7, 4)).unwrap();
+            assert_eq!(
+                coords,
+                vec![
+                    (6, 3),
+                    (7, 3),
+                    (8, 3),
+                    (6, 4),
+                    (7, 4),
+                    (8, 4),
+                    (6, 5),
+                    (7, 5),
+                    (8, 5)
+                ]
+            );
+            let values: Vec<_> = coords.into_iter().map(|c| grid.get(c)).collect();
+            let expected: Vec<_> = grid.nrant_iter(3, (7, 4)).collect();
+            assert_eq!(values, expected);
+        }
+
+        #[test]
+        fn uneven_nrant_coords() {
+            let vec = (0..20).collect();
+            let grid = Grid::new_from_1d(vec, 4, 5, None).unwrap();
+            // Quadrants are 2 wide and 3 high, the lower quadrants only have 2 rows in the grid
+            let coords = grid.nrant_coords(2, (3, 4)).unwrap();
+            assert_eq!(coords, vec![(2, 3), (3, 3), (2, 4), (3, 4)]);
+
+            let vec = vec![vec![0, 1, 2], vec![3, 4, 5]];
+            let grid = Grid::new(vec, None).unwrap();
+            let coords = grid.nrant_coords(2, (2, 1)).unwrap();
+            assert_eq!(coords, vec![(2, 1)]);
+
+            assert!(matches!(
+                grid.nrant_coords(4, (2, 1)),
+                Err(GridError::InvalidDivisionSize)
+            ));
+        }
+
+        #[test]
+        fn uneven_row_quadrant_start() {
+            let vec = (0..20).collect();
+            let grid = Grid::new_from_1d(vec, 4, 5, None).unwrap();
+
+            // Quadrants are 3 rows high, so the lower quadrants start on row 3
+            assert_eq!(grid.nrant(17, 2).unwrap(), 2);
+            assert_eq!(grid.nrant_start(17, 2), 12);
+            assert_eq!(grid.nrant_start(8, 2), 0);
+            assert_eq!(grid.nrant_start(19, 2), 14);
+
+            let mut iter = grid.quadrant_iter(17);
+            assert_eq!(iter.next(), Some(Some(&12)));
+            assert_eq!(iter.next(), Some(Some(&13)));
+            assert_eq!(iter.next(), Some(Some(&16)));
+            assert_eq!(iter.next(), Some(Some(&17)));
+            assert_eq!(iter.next(), Some(None));
+            assert_eq!(iter.next(), Some(None));
+            assert_eq!(iter.next(), None);
+        }
+    }
+
+    mod row_iters {
+        use super::*;
+
+        #[test]
+        fn should_return_none_outside_bounds() {
+            let grid = center_grid();
+            let mut iter = grid.row_iter((2, 0));
+            assert_eq!(iter.next(), None);
+            assert_eq!(iter.next(), None);
+        }
+
+        #[test]
+        fn should_return_none_outside_bounds_mut() {
+            let mut grid = center_grid();
+            let mut iter = grid.row_iter_mut((2, 0));
+            assert_eq!(iter.next(), None);
+            assert_eq!(iter.next(), None);
+        }
+
+        #[test]
+        fn should_report_exact_len() {
+            let mut grid = center_grid();
+            for i in 0..grid.size() {
+                assert_eq!(grid.row_iter(i).len(), grid.columns());
+            }
+            let mut iter = grid.row_iter((0, 1));
+            iter.next();
+            assert_eq!(iter.len(), grid.columns() - 1);
+            iter.next_back();
+            assert_eq!(iter.len(), grid.columns() - 2);
+
+            assert_eq!(grid.row_iter((2, 0)).len(), 0);
+            assert_eq!(grid.row_iter_mut((0, 1)).len(), 3);
+            assert_eq!(grid.row_iter_mut((2, 0)).len(), 0);
+        }
+
+        #[test]
+        fn should_iter_mutably() {
+            let mut grid = center_grid();
+            for cell in grid.row_iter_mut((0, 1)) {
+                *cell += 1;
+            }
+            let mut iter = grid.row_iter((0, 1));
+            assert_eq!(iter.next(), Some(&4));
+            assert_eq!(iter.next(), Some(&5));
+            assert_eq!(iter.next(), Some(&6));
+            assert_eq!(iter.next(), None);
+        }
+
+        #[test]
+        fn should_iter_row_by_y() {
+            let grid = center_grid();
+            let row: Vec<_> = grid.iter_row(2).collect();
+            assert_eq!(row, vec![&0, &1, &2]);
+            let row: Vec<_> = grid.iter_row(-1).collect();
+            assert_eq!(row, vec![&9, &10, &11]);
+            let row: Vec<_> = grid.iter_row(-2).rev().collect();
+            assert_eq!(row, vec![&14, &13, &12]);
+            for y in -2..=2 {
+                assert!(grid.iter_row(y).eq(grid.row_iter((1, y))));
+            }
+            assert_eq!(grid.iter_row(3).next(), None);
+            assert_eq!(grid.iter_row(-3).len(), 0);
+        }
+    }
+
+    mod col_iters {
+        use super::*;
+
+        #[test]
+        fn should_return_none_outside_bounds() {
+            let grid = center_grid();
+            let mut iter = grid.col_iter((-4, 0));
+            assert_eq!(iter.next(), None);
+            assert_eq!(iter.next(), None);
+        }
+
+        #[test]
+        fn should_return_none_outside_bounds_mut() {
+            let mut grid = center_grid();
+            let mut iter = grid.col_iter_mut((-4, 0));
+            assert_eq!(iter.next(), None);
+            assert_eq!(iter.next(), None);
+        }
+
+        #[test]
+        fn should_report_exact_len() {
+            let mut grid = center_grid();
+            for i in 0..grid.size() {
+                assert_eq!(grid.col_iter(i).len(), grid.rows());
+            }
+            let mut iter = grid.col_iter((0, 1));
+            iter.next();
+            assert_eq!(iter.len(), grid.rows() - 1);
+            iter.next_back();
+            assert_eq!(iter.len(), grid.rows() - 2);
+
+            assert_eq!(grid.col_iter((-4, 0)).len(), 0);
+            assert_eq!(grid.col_iter_mut((0, 1)).len(), 5);
+            assert_eq!(grid.col_iter_mut((-4, 0)).len(), 0);
+        }
+
+        #[test]
+        fn should_iter_mutably() {
+            let mut grid = center_grid();
+            for cell in grid.col_iter_mut((0, 1)) {
+                *cell += 1;
+            }
+            let mut iter = grid.col_iter((0, 1));
+            assert_eq!(iter.next(), Some(&2));
+            assert_eq!(iter.next(), Some(&5));
+            assert_eq!(iter.next(), Some(&8));
+            assert_eq!(iter.next(), Some(&11));
+            assert_eq!(iter.next(), Some(&14));
+            assert_eq!(iter.next(), None);
+        }
+
+        #[test]
+        fn should_iter_col_by_x() {
+            let grid = center_grid();
+            let col: Vec<_> = grid.iter_col(-1).collect();
+            assert_eq!(col, vec![&0, &3, &6, &9, &12]);
+            let col: Vec<_> = grid.iter_col(1).rev().collect();
+            assert_eq!(col, vec![&14, &11, &8, &5, &2]);
+            for x in -1..=1 {
+                assert!(grid.iter_col(x).eq(grid.col_iter((x, -2))));
+            }
+            assert_eq!(grid.iter_col(2).next(), None);
+            assert_eq!(grid.iter_col(-2).len(), 0);
+        }
+    }
+    mod rearrange {
+        use super::*;
+
+        #[test]
+        fn should_swap_rows() {
+            let mut grid = center_grid();
+            grid.swap_rows(0, 4).unwrap();
+            assert_eq!(
+                grid.items,
+                vec![12, 13, 14, 3, 4, 5, 6, 7, 8, 9, 10, 11, 0, 1, 2]
+            );
+            assert_eq!(grid.get((-1, 2)), Some(&12));
+
+            grid.swap_rows(4, 0).unwrap();
+            assert_eq!(grid, center_grid());
+            grid.swap_rows(2, 2).unwrap();
+            assert_eq!(grid, center_grid());
+        }
+
+        #[test]
+        fn should_swap_cols() {
+            let mut grid = center_grid();
+            grid.swap_cols(0, 2).unwrap();
+            assert_eq!(
+                grid.items,
+                vec![2, 1, 0, 5, 4, 3, 8, 7, 6, 11, 10, 9, 14, 13, 12]
+            );
+            grid.swap_cols(1, 1).unwrap();
+            grid.swap_cols(2, 0).unwrap();
+            assert_eq!(grid, center_grid());
+        }
+
+        #[test]
+        fn should_reverse_row_order() {
+            let mut grid = center_grid();
+            grid.reverse_row_order();
+            assert_eq!(
+                grid.items,
+                vec![12, 13, 14, 9, 10, 11, 6, 7, 8, 3, 4, 5, 0, 1, 2]
+            );
+            assert_eq!(grid.options, center_grid().options);
+            assert_eq!(grid.get((-1, 2)), Some(&12));
+            grid.reverse_row_order();
+            assert_eq!(grid, center_grid());
+
+            let mut grid = Grid::new(vec![vec![0, 1, 2, 3]], None).unwrap();
+            grid.reverse_row_order();
+            assert_eq!(grid.items, vec![0, 1, 2, 3]);
+        }
+
+        #[test]
+        fn should_reverse_col_order() {
+            let mut grid = center_grid();
+            grid.reverse_col_order();
+            assert_eq!(
+                grid.items,
+                vec![2, 1, 0, 5, 4, 3, 8, 7, 6, 11, 10, 9, 14, 13, 12]
+            );
+            assert_eq!(grid.get((-1, 2)), Some(&2));
+            grid.reverse_col_order();
+            assert_eq!(grid, center_grid());
+
+            let mut grid = Grid::new(vec![vec![0], vec![1], vec![2]], None).unwrap();
+            grid.reverse_col_order();
+            assert_eq!(grid.items, vec![0, 1, 2]);
+        }
+
+        #[test]
+        fn should_rotate_in_place() {
+            for size in [1, 2, 3, 4, 5] {
+                let mut grid =
+                    Grid::new_from_1d((0..size * size).collect(), size, size, None).unwrap();
+                let rotated: Vec<_> = grid
+                    .cols_iter()
+                    .flat_map(|col| col.rev())
+                    .copied()
+                    .collect();
+                grid.rotate_cw_in_place().unwrap();
+                assert_eq!(grid.items, rotated, "{}x{}", size, size);
+            }
+
+            let mut grid = Grid::new_from_1d((0..16).collect(), 4, 4, None).unwrap();
+            grid.rotate_cw_in_place().unwrap();
+            assert_eq!(
+                grid.items,
+                vec![12, 8, 4, 0, 13, 9, 5, 1, 14, 10, 6, 2, 15, 11, 7, 3]
+            );
+            for _ in 0..3 {
+                grid.rotate_cw_in_place().unwrap();
+            }
+            assert_eq!(grid.items, (0..16).collect::<Vec<_>>());
+        }
+
+        #[test]
+        fn should_err_rotating_non_square_grid() {
+            let mut grid = center_grid();
+            assert!(matches!(
+                grid.rotate_cw_in_place(),
+                Err(GridError::InvalidSize)
+            ));
+            assert_eq!(grid, center_grid());
+        }
+
+        #[test]
+        fn should_rotate_180() {
+            for size in [1, 2, 3, 4] {
+                let mut grid =
+                    Grid::new_from_1d((0..size * size).collect(), size, size, None).unwrap();
+                let mut twice = grid.clone();
+                twice.rotate_cw_in_place().unwrap();
+                twice.rotate_cw_in_place().unwrap();
+                grid.rotate_180();
+                assert_eq!(grid, twice, "{}x{}", size, size);
+            }
+
+            let mut grid = center_grid();
+            grid.rotate_180();
+            assert_eq!((grid.rows(), grid.columns()), (5, 3));
+            assert_eq!(grid.options, center_grid().options);
+            assert_eq!(grid.get((-1, 2)), Some(&14));
+            assert_eq!(grid.get((0, 0)), Some(&7));
+            let mut flipped = center_grid();
+            flipped.reverse_row_order();
+            flipped.reverse_col_order();
+            assert_eq!(grid, flipped);
+            grid.rotate_180();
+            assert_eq!(grid, center_grid());
+        }
+
+        #[test]
+        fn should_shift_with_wrap() {
+            let mut grid = center_grid();
+            grid.shift(1, 2, true, None);
+            assert_eq!(
+                grid.items,
+                vec![11, 9, 10, 14, 12, 13, 2, 0, 1, 5, 3, 4, 8, 6, 7]
+            );
+            grid.shift(-1, -2, true, None);
+            assert_eq!(grid, center_grid());
+
+            // Shifting by the full size is a full loop around the grid
+            grid.shift(-3, 10, true, None);
+            assert_eq!(grid, center_grid());
+        }
+
+        #[test]
+        fn should_shift_with_fill() {
+            let mut grid = center_grid();
+            grid.shift(1, 2, false, Some(-1));
+            assert_eq!(
+                grid.items,
+                vec![-1, -1, -1, -1, -1, -1, -1, 0, 1, -1, 3, 4, -1, 6, 7]
+            );
+
+            let mut grid = center_grid();
+            grid.shift(-2, -1, false, Some(-1));
+            assert_eq!(
+                grid.items,
+                vec![5, -1, -1, 8, -1, -1, 11, -1, -1, 14, -1, -1, -1, -1, -1]
+            );
+
+            let mut grid = center_grid();
+            grid.shift(3, 0, false, Some(0));
+            assert!(grid.iter().all(|c| *c == 0));
+        }
+
+        #[test]
+        fn should_shift_extreme_offsets_off_the_grid() {
+            for (dx, dy) in [
+                (isize::MAX, 0),
+                (isize::MIN, 0),
+                (0, isize::MAX),
+                (0, isize::MIN),
+            ] {
+                let mut grid = center_grid();
+                grid.shift(dx, dy, false, Some(-1));
+                assert!(grid.iter().all(|c| *c == -1), "({}, {})", dx, dy);
+            }
+
+            let mut grid = center_grid();
+            grid.shift(isize::MAX, isize::MIN, true, None);
+            assert_eq!(grid.size(), 15);
+        }
+
+        #[test]
+        #[should_panic]
+        fn should_panic_shifting_without_fill() {
+            let mut grid = center_grid();
+            grid.shift(1, 0, false, None);
+        }
+
+        #[test]
+        fn should_err_swapping_invalid_row_or_column() {
+            let mut grid = center_grid();
+            assert!(matches!(
+                grid.swap_rows(0, 5),
+                Err(GridError::IndexOutOfBounds { .. })
+            ));
+            assert!(matches!(
+                grid.swap_rows(5, 0),
+                Err(GridError::IndexOutOfBounds { .. })
+            ));
+            assert!(matches!(
+                grid.swap_cols(3, 0),
+                Err(GridError::IndexOutOfBounds { .. })
+            ));
+            assert_eq!(grid, center_grid());
+        }
+    }
+
+    mod map_lines {
+        use super::*;
+
+        #[test]
+        fn should_zero_each_row_minimum() {
+            let mut grid = center_grid();
+            grid.map_rows(|row| {
+                let min = *row.iter().min().unwrap();
+                row.iter_mut().for_each(|cell| *cell -= min);
+            });
+            assert_eq!(grid.to_2d_vec(), vec![vec![0, 1, 2]; 5]);
+        }
+
+        #[test]
+        fn should_pass_rows_in_order() {
+            let mut grid = center_grid();
+            let mut seen = vec![];
+            grid.map_rows(|row| seen.push(row.to_vec()));
+            assert_eq!(seen, center_grid().to_2d_vec());
+        }
+
+        #[test]
+        fn should_zero_each_col_minimum() {
+            let mut grid = center_grid();
+            let mut seen = vec![];
+            grid.map_cols(|col| {
+                seen.push(col.to_vec());
+                let min = *col.iter().min().unwrap();
+                col.iter_mut().for_each(|cell| *cell -= min);
+            });
+            assert_eq!(seen[1], vec![1, 4, 7, 10, 13]);
+            assert_eq!(
+                grid.to_2d_vec(),
+                vec![
+                    vec![0, 0, 0],
+                    vec![3, 3, 3],
+                    vec![6, 6, 6],
+                    vec![9, 9, 9],
+                    vec![12, 12, 12]
+                ]
+            );
+        }
+    }
+
+    mod fill_rect {
+        use super::*;
+
+        #[test]
+        fn should_fill_block() {
+            let mut grid = center_grid();
+            grid.fill_rect((-1, 1), 2, 2, 0).unwrap();
+            assert_eq!(
+                grid.to_2d_vec(),
+                vec![
+                    vec![0, 1, 2],
+                    vec![0, 0, 5],
+                    vec![0, 0, 8],
+                    vec![9, 10, 11],
+                    vec![12, 13, 14]
+                ]
+            );
+
+            let mut grid = wrap_grid(false, false);
+            grid.fill_rect((2, 4), 1, 1, -1).unwrap();
+            assert_eq!(grid.get((2, 4)), Some(&-1));
+            assert_eq!(grid.iter().filter(|v| **v < 0).count(), 1);
+            grid.fill_rect((0, 0), 0, 5, -1).unwrap();
+            assert_eq!(grid.iter().filter(|v| **v < 0).count(), 1);
+        }
+
+        #[test]
+        fn should_err_when_block_does_not_fit() {
+            let mut grid = wrap_grid(false, false);
+            assert!(matches!(
+                grid.fill_rect((1, 3), 2, 3, 0),
+                Err(GridError::IndexOutOfBounds { x: 2, y: 5 })
+            ));
+            assert!(matches!(
+                grid.fill_rect((1, 0), 3, 1, 0),
+                Err(GridError::IndexOutOfBounds { x: 3, y: 0 })
+            ));
+            assert!(matches!(
+                grid.fill_rect((3, 0), 1, 1, 0),
+                Err(GridError::IndexOutOfBounds { x: 3, y: 0 })
+            ));
+            assert_eq!(grid, wrap_grid(false, false));
+        }
+    }
+
+    mod blit {
+        use super::*;
+
+        #[test]
+        fn should_blit_into_middle() {
+            let mut grid = center_grid();
+            let src = Grid::new(vec![vec![-1, -2], vec![-3, -4]], None).unwrap();
+            grid.blit(&src, (0, 0)).unwrap();
+            assert_eq!(
+                grid.to_2d_vec(),
+                vec![
+                    vec![0, 1, 2],
+                    vec![3, 4, 5],
+                    vec![6, -1, -2],
+                    vec![9, -3, -4],
+                    vec![12, 13, 14]
+                ]
+            );
+            assert_eq!(grid.get((0, 0)), Some(&-1));
+            assert_eq!(grid.get((1, -1)), Some(&-4));
+            assert_eq!(grid.get((-1, 0)), Some(&6));
+        }
+
+        #[test]
+        fn should_err_when_src_does_not_fit() {
+            let mut grid = center_grid();
+            let src = Grid::new(vec![vec![-1, -2], vec![-3, -4]], None).unwrap();
+            assert!(matches!(
+                grid.blit(&src, (1, 0)),
+                Err(GridError::IndexOutOfBounds { x: 2, y: -1 })
+            ));
+            assert!(matches!(
+                grid.blit(&src, (0, -2)),
+                Err(GridError::IndexOutOfBounds { x: 1, y: -3 })
+            ));
+            assert_eq!(grid, center_grid());
+
+            let same_size = center_grid().try_map(|v| Ok::<_, ()>(v * 2)).unwrap();
+            grid.blit(&same_size, (-1, 2)).unwrap();
+            assert_eq!(grid, same_size);
+        }
+
+        #[test]
+        fn should_do_nothing_for_empty_src() {
+            let mut grid = center_grid();
+            grid.blit(&Grid::empty(None), (0, 0)).unwrap();
+            assert_eq!(grid, center_grid());
+        }
+    }
+
+    mod empty {
+        use super::*;
+
+        #[test]
+        fn should_have_no_cells() {
+            for origin in Origin::ALL {
+                let options = GridOptions::default().with_origin(origin);
+                let grid: Grid<i32> = Grid::empty(Some(options));
+                assert!(grid.is_empty());
+                assert_eq!((grid.rows(), grid.columns()), (0, 0));
+                assert_eq!(grid.iter().count(), 0);
+                assert_eq!(grid.coordinates().count(), 0);
+                assert_eq!(grid.get((0, 0)), None);
+                assert!(matches!(grid.get_result(0), Err(GridError::EmptyGrid)));
+                assert!(matches!(grid.get_result((0, 0)), Err(GridError::EmptyGrid)));
+                assert!(grid.to_2d_vec().is_empty());
+                assert_eq!(grid.boundary_iter().count(), 0);
+                assert_eq!(grid.anti_diagonals().count(), 0);
+            }
+        }
+
+        #[test]
+        fn should_not_panic_when_shifted() {
+            let mut grid: Grid<i32> = Grid::empty(None);
+            grid.shift(1, 1, true, None);
+            grid.shift(-1, 2, false, Some(0));
+            assert!(grid.is_empty());
+        }
+
+        #[test]
+        fn should_grow_from_empty() {
+            let mut grid = Grid::empty(None);
+            grid.insert_row(0, vec![1, 2, 3]).unwrap();
+            grid.insert_row(1, vec![4, 5, 6]).unwrap();
+            assert_eq!((grid.rows(), grid.columns()), (2, 3));
+            assert_eq!(grid.to_2d_vec(), vec![vec![1, 2, 3], vec![4, 5, 6]]);
+
+            let mut grid = Grid::empty(None);
+            grid.insert_column(0, vec![1, 2]).unwrap();
+            assert_eq!((grid.rows(), grid.columns()), (2, 1));
+            assert_eq!(grid.to_2d_vec(), vec![vec![1], vec![2]]);
+
+            let grid = Grid::empty(None).pad(1, 2, 0, 0, 0).unwrap();
+            assert_eq!((grid.rows(), grid.columns()), (0, 0));
+            let grid = Grid::empty(None).pad(0, 0, 3, 0, 0).unwrap();
+            assert_eq!((grid.rows(), grid.columns()), (0, 0));
+            assert_eq!(grid.boundary_iter().count(), 0);
+            assert_eq!(grid.rows_iter().count(), 0);
+            let grid = Grid::empty(None).pad(1, 0, 0, 1, 0).unwrap();
+            assert_eq!(grid.to_2d_vec(), vec![vec![0]]);
+
+            let mut grid: Grid<i32> = Grid::empty(None);
+            assert!(grid.insert_row(0, Vec::new()).is_err());
+            assert!(grid.insert_row(1, vec![1]).is_err());
+            assert!(grid.is_empty());
+        }
+    }
+
+    mod resize {
+        use super::*;
+
+        #[test]
+        fn should_pad_all_sides() {
+            let grid = center_grid();
+            let padded = grid.pad(1, 1, 1, 1, -1).unwrap();
+            assert_eq!(padded.rows(), 7);
+            assert_eq!(padded.columns(), 5);
+            assert_eq!(padded.options, grid.options);
+            assert_eq!(padded.get_row(0), Some(&[-1; 5][..]));
+            assert_eq!(padded.get_row(1), Some(&[-1, 0, 1, 2, -1][..]));
+            assert_eq!(padded.get_row(5), Some(&[-1, 12, 13, 14, -1][..]));
+            assert_eq!(padded.get_row(6), Some(&[-1; 5][..]));
+            // Center origin, so the middle cell is still at (0, 0)
+            assert_eq!(padded.get((0, 0)), Some(&7));
+            assert_eq!(padded.get((-2, 3)), Some(&-1));
+            assert_eq!(padded.get((2, -3)), Some(&-1));
+            assert_eq!(padded.get((-1, 2)), Some(&0));
+            assert_eq!(padded.count_eq(&-1), 35 - 15);
+        }
+
+        #[test]
+        fn should_crop_edges() {
+            let grid = center_grid();
+            let cropped = grid.crop(1, 0, 1, 2).unwrap();
+            assert_eq!(cropped.rows(), 2);
+            assert_eq!(cropped.columns(), 2);
+            assert_eq!(cropped.items, vec![4, 5, 7, 8]);
+            assert_eq!(cropped.options, grid.options);
+
+            let cropped = grid.crop(1, 1, 2, 2).unwrap();
+            assert_eq!(cropped.items, vec![7]);
+            assert_eq!(cropped.get((0, 0)), Some(&7));
+
+            assert_eq!(grid.crop(0, 0, 0, 0).unwrap(), grid);
+            assert_eq!(
+                grid.pad(2, 1, 3, 1, 0).unwrap().crop(2, 1, 3, 1).unwrap(),
+                grid
+            );
+        }
+
+        #[test]
+        fn should_err_cropping_everything() {
+            let grid = center_grid();
+            assert!(matches!(grid.crop(2, 1, 0, 0), Err(GridError::InvalidSize)));
+            assert!(matches!(grid.crop(0, 0, 5, 0), Err(GridError::InvalidSize)));
+            assert!(matches!(grid.crop(0, 0, 3, 3), Err(GridError::InvalidSize)));
+            assert!(matches!(
+                grid.crop(usize::MAX, usize::MAX, 0, 0),
+                Err(GridError::InvalidSize)
+            ));
+        }
+
+        #[test]
+        fn should_pad_uneven_sides() {
+            let grid = Grid::new(vec![vec![1, 2], vec![3, 4]], None).unwrap();
+            let padded = grid.pad(0, 2, 1, 0, 0).unwrap();
+            assert_eq!(
+                padded.to_2d_vec(),
+                vec![vec![0, 0, 0, 0], vec![1, 2, 0, 0], vec![3, 4, 0, 0]]
+            );
+            assert_eq!(grid.pad(0, 0, 0, 0, 0).unwrap(), grid);
+        }
+
+        #[test]
+        fn should_err_on_excessive_padding() {
+            let grid = center_grid();
+            for (left, right, top, bottom) in [
+                (usize::MAX, 1, 0, 0),
+                (0, 0, 1, usize::MAX),
+                (usize::MAX - 3, 0, 0, 0),
+                (0, 0, i32::MAX as usize, 0),
+                (1 << 16, 0, 1 << 16, 0),
+            ] {
+                assert!(matches!(
+                    grid.pad(left, right, top, bottom, 0),
+                    Err(GridError::ExcessiveSize)
+                ));
+            }
+        }
+
+        #[test]
+        fn should_retain_rows() {
+            let mut grid = center_grid();
+            grid.retain_rows(|row| row.iter().sum::<i32>() % 2 != 0)
+                .unwrap();
+            assert_eq!(grid.rows(), 3);
+            assert_eq!(grid.columns(), 3);
+            assert_eq!(grid.items, vec![0, 1, 2, 6, 7, 8, 12, 13, 14]);
+
+            grid.retain_rows(|_| true).unwrap();
+            assert_eq!(grid.rows(), 3);
+        }
+
+        #[test]
+        fn should_retain_cols() {
+            let mut grid = center_grid();
+            let mut visited = vec![];
+            grid.retain_cols(|col| {
+                visited.push(*col[0]);
+                col.iter().any(|c| **c == 13)
+            })
+            .unwrap();
+            assert_eq!(visited, vec![0, 1, 2]);
+            assert_eq!(grid.rows(), 5);
+            assert_eq!(grid.columns(), 1);
+            assert_eq!(grid.items, vec![1, 4, 7, 10, 13]);
+        }
+
+        #[test]
+        fn should_err_retaining_nothing() {
+            let mut grid = center_grid();
+            assert!(matches!(
+                grid.retain_rows(|_| false),
+                Err(GridError::InvalidSize)
+            ));
+            assert!(matches!(
+                grid.retain_cols(|_| false),
+                Err(GridError::InvalidSize)
+            ));
+            assert_eq!(grid, center_grid());
+        }
+
+        #[test]
+        fn should_remove_row() {
+            let mut grid = center_grid();
+            let removed = grid.remove_row(1).unwrap();
+            assert_eq!(removed, vec![3, 4, 5]);
+            assert_eq!(grid.rows(), 4);
+            assert_eq!(grid.columns(), 3);
+            assert_eq!(grid.size(), 12);
+            assert_eq!(grid.items, vec![0, 1, 2, 6, 7, 8, 9, 10, 11, 12, 13, 14]);
+
+            let removed = grid.remove_row(3).unwrap();
+            assert_eq!(removed, vec![12, 13, 14]);
+            assert_eq!(grid.rows(), 3);
+            assert_eq!(grid.items, vec![0, 1, 2, 6, 7, 8, 9, 10, 11]);
+        }
+
+        #[test]
+        fn should_remove_column() {
+            let mut grid = center_grid();
+            let removed = grid.remove_column(1).unwrap();
+            assert_eq!(removed, vec![1, 4, 7, 10, 13]);
+            assert_eq!(grid.rows(), 5);
+            assert_eq!(grid.columns(), 2);
+            assert_eq!(grid.size(), 10);
+            assert_eq!(grid.items, vec![0, 2, 3, 5, 6, 8, 9, 11, 12, 14]);
+
+            let removed = grid.remove_column(1).unwrap();
+            assert_eq!(removed, vec![2, 5, 8, 11, 14]);
+            assert_eq!(grid.columns(), 1);
+            assert_eq!(grid.items, vec![0, 3, 6, 9, 12]);
+        }
+
+        #[test]
+        fn should_err_removing_invalid_row_or_column() {
+            let mut grid = center_grid();
+            assert!(matches!(
+                grid.remove_row(6),
+                Err(GridError::IndexOutOfBounds { x: -1, y: -4 })
+            ));
+            assert!(matches!(
+                grid.remove_row(5),
+                Err(GridError::IndexOutOfBounds { .. })
+            ));
+            assert!(matches!(
+                grid.remove_column(3),
+                Err(GridError::IndexOutOfBounds { .. })
+            ));
+            assert_eq!(grid, center_grid());
+        }
+
+        #[test]
+        fn should_insert_row() {
+            let mut grid = center_grid();
+            grid.insert_row(2, vec![20, 21, 22]).unwrap();
+            assert_eq!(grid.rows(), 6);
+            assert_eq!(grid.columns(), 3);
+            assert_eq!(
+                grid.items,
+                vec![0, 1, 2, 3, 4, 5, 20, 21, 22, 6, 7, 8, 9, 10, 11, 12, 13, 14]
+            );
+
+            grid.insert_row(6, vec![30, 31, 32]).unwrap();
+            assert_eq!(grid.rows(), 7);
+            assert_eq!(
+                grid.row_iter(20).copied().collect::<Vec<_>>(),
+                vec![30, 31, 32]
+            );
+        }
+
+        #[test]
+        fn should_insert_column() {
+            let mut grid = center_grid();
+            grid.insert_column(1, vec![20, 21, 22, 23, 24]).unwrap();
+            assert_eq!(grid.rows(), 5);
+            assert_eq!(grid.columns(), 4);
+            assert_eq!(
+                grid.items,
+                vec![0, 20, 1, 2, 3, 21, 4, 5, 6, 22, 7, 8, 9, 23, 10, 11, 12, 24, 13, 14]
+            );
+
+            grid.insert_column(4, vec![30, 31, 32, 33, 34]).unwrap();
+            assert_eq!(grid.columns(), 5);
+            assert_eq!(
+                grid.col_iter(4).copied().collect::<Vec<_>>(),
+                vec![30, 31, 32, 33, 34]
+            );
+            assert_eq!(
+                grid.row_iter(0).copied().collect::<Vec<_>>(),
+                vec![0, 20, 1, 2, 30]
+            );
+        }
+
+        #[test]
+        fn should_err_inserting_invalid_row_or_column() {
+            let mut grid = center_grid();
+            assert!(matches!(
+                grid.insert_row(1, vec![1, 2]),
+                Err(GridError::RowSizeMismatch {
+                    row: 1,
+                    expected: 3,
+                    actual: 2
+                })
+            ));
+            assert!(matches!(
+                grid.insert_row(6, vec![1, 2, 3]),
+                Err(GridError::IndexOutOfBounds { .. })
+            ));
+            assert!(matches!(
+                grid.insert_column(1, vec![1, 2, 3]),
+                Err(GridError::RowSizeMismatch {
+                    row: 1,
+                    expected: 5,
+                    actual: 3
+                })
+            ));
+            assert!(matches!(
+                grid.insert_column(4, vec![1, 2, 3, 4, 5]),
+                Err(GridError::IndexOutOfBounds { .. })
+            ));
+            assert_eq!(grid, center_grid());
+        }
+
+        #[test]
+        fn should_err_removing_last_row_or_column() {
+            let mut grid = Grid::new(vec![vec![1, 2]], None).unwrap();
+            assert!(matches!(grid.remove_row(0), Err(GridError::InvalidSize)));
+            grid.remove_column(0).unwrap();
+            assert!(matches!(grid.remove_column(0), Err(GridError::InvalidSize)));
+            assert_eq!(grid.items, vec![2]);
+        }
+    }
+
+    mod search {
+        use super::*;
+
+        #[test]
+        fn should_find_position() {
+            let grid = center_grid();
+            assert_eq!(grid.position(|v| *v == 7), Some((0, 0)));
+            assert_eq!(grid.position(|v| *v == 12), Some((-1, -2)));
+            assert_eq!(grid.position(|v| *v > 3), Some((0, 1)));
+            assert_eq!(grid.position(|v| *v > 14), None);
+        }
+
+        #[test]
+        fn should_find_positions() {
+            let grid = center_grid();
+            let positions = grid.positions(|v| v % 5 == 0);
+            assert_eq!(positions, vec![(-1, 2), (1, 1), (0, -1)]);
+            for coord in positions {
+                assert_eq!(grid.get(coord).unwrap() % 5, 0);
+            }
+            assert!(grid.positions(|v| *v < 0).is_empty());
+        }
+    }
+
+    mod extremes {
+        use super::*;
+
+        #[test]
+        fn should_find_max_and_min_coordinates() {
+            let grid = center_grid();
+            assert_eq!(grid.max_by_key(|v| *v), Some(((1, -2), &14)));
+            assert_eq!(grid.min_by_key(|v| *v), Some(((-1, 2), &0)));
+            // Distance from 7, which is at the origin
+            assert_eq!(grid.min_by_key(|v| (v - 7).abs()), Some(((0, 0), &7)));
+        }
+
+        #[test]
+        fn should_break_ties_by_first_cell() {
+            let grid = center_grid();
+            assert_eq!(grid.max_by_key(|v| v % 5), Some(((0, 1), &4)));
+            assert_eq!(grid.min_by_key(|v| v % 5), Some(((-1, 2), &0)));
+            assert_eq!(grid.max_by_key(|_| 1), Some(((-1, 2), &0)));
+        }
+    }
 
-        let vec = vec![vec![1; 1000]; u16::MAX as usize];
-        let grid = vec.into_grid()?;
-        assert_eq!(grid.rows, u16::MAX as usize);
-        assert_eq!(grid.cols, 1000);
+    mod zip_map {
+        use super::*;
+
+        #[test]
+        fn should_add_grids() {
+            let a = center_grid();
+            let b = Grid::new_from_1d((0..15).map(|v| v * 10).collect(), 3, 5, None).unwrap();
+            let sum = a.zip_map(&b, |a, b| a + b).unwrap();
+            assert_eq!(sum.options, a.options);
+            assert_eq!(sum.get((0, 0)), Some(&77));
+            assert_eq!(sum.get((1, -2)), Some(&154));
+            assert_eq!(sum.rows(), 5);
+            assert_eq!(sum.columns(), 3);
+
+            let labels = a.zip_map(&b, |a, b| format!("{}:{}", a, b)).unwrap();
+            assert_eq!(labels.get((-1, 2)), Some(&"0:0".to_string()));
+        }
+
+        #[test]
+        fn should_err_on_different_sizes() {
+            let a = center_grid();
+            // Same number of cells, different shape
+            let b = Grid::new_from_1d((0..15).collect(), 5, 3, None).unwrap();
+            assert!(matches!(
+                a.zip_map(&b, |a, b| a + b),
+                Err(GridError::InvalidSize)
+            ));
+        }
+    }
+
+    mod try_map {
+        use super::*;
+
+        #[test]
+        fn should_map_when_all_ok() {
+            let grid = center_grid();
+            let strings = grid.try_map(|v| Ok::<_, ()>(v.to_string())).unwrap();
+            let parsed = strings.try_map(|s| s.parse::<i32>()).unwrap();
+            assert_eq!(parsed, grid);
+            assert_eq!(strings.get((0, 0)), Some(&"7".to_string()));
+            assert_eq!(strings.options, grid.options);
+        }
+
+        #[test]
+        fn should_stop_at_first_error() {
+            let grid = center_grid();
+            let mut calls = 0;
+            let result = grid.try_map(|v| {
+                calls += 1;
+                if *v >= 4 {
+                    Err(*v)
+                } else {
+                    Ok(*v)
+                }
+            });
+            assert_eq!(result, Err(4));
+            assert_eq!(calls, 5);
+        }
+    }
+
+    mod step {
+        use super::*;
+
+        fn life(grid: &Grid<bool>, coord: (isize, isize), alive: &bool) -> bool {
+            let neighbors = grid.all_around_neighbors(coord).unwrap();
+            let count = neighbors.iter().filter(|n| **n == Some(&true)).count();
+            matches!((alive, count), (true, 2) | (_, 3))
+        }
+
+        fn glider(cells: [[u8; 5]; 5]) -> Grid<bool> {
+            let gridoptions = GridOptions {
+                wrap_x: true,
+                wrap_y: true,
+                ..GridOptions::default()
+            };
+            let cells = cells.map(|row| row.map(|c| c == 1));
+            Grid::new(cells, Some(gridoptions)).unwrap()
+        }
+
+        #[test]
+        fn should_step_game_of_life() {
+            let mut grid = glider([
+                [0, 1, 0, 0, 0],
+                [0, 0, 1, 1, 0],
+                [0, 1, 1, 0, 0],
+                [0, 0, 0, 0, 0],
+                [0, 0, 0, 0, 0],
+            ]);
+            grid.step(life);
+            let second = glider([
+                [0, 0, 1, 0, 0],
+                [0, 0, 0, 1, 0],
+                [0, 1, 1, 1, 0],
+                [0, 0, 0, 0, 0],
+                [0, 0, 0, 0, 0],
+            ]);
+            assert_eq!(grid, second);
+
+            grid.step(life);
+            let third = glider([
+                [0, 0, 0, 0, 0],
+                [0, 1, 0, 1, 0],
+                [0, 0, 1, 1, 0],
+                [0, 0, 1, 0, 0],
+                [0, 0, 0, 0, 0],
+            ]);
+            assert_eq!(grid, third);
+
+            // A glider moves one cell diagonally every 4 generations, so it wraps back to the start of a 5x5 grid after 20
+            for _ in 0..18 {
+                grid.step(life);
+            }
+            let first = glider([
+                [0, 1, 0, 0, 0],
+                [0, 0, 1, 1, 0],
+                [0, 1, 1, 0, 0],
+                [0, 0, 0, 0, 0],
+                [0, 0, 0, 0, 0],
+            ]);
+            assert_eq!(grid, first);
+        }
+
+        #[test]
+        fn should_see_previous_generation() {
+            let mut grid = Grid::new(vec![vec![1, 2, 3, 4]], None).unwrap();
+            grid.step(|grid, coord, cell| cell + grid.get_left(coord).unwrap_or(&0));
+            assert_eq!(grid.to_2d_vec(), vec![vec![1, 3, 5, 7]]);
+        }
+    }
+
+    mod iter_masked {
+        use super::*;
+
+        #[test]
+        fn should_yield_checkerboard() {
+            let grid = center_grid();
+            let black: Vec<_> = grid
+                .iter_masked(|(x, y)| (x + y).rem_euclid(2) == 0)
+                .collect();
+            assert_eq!(black.len(), 7);
+            assert_eq!(black[0], ((0, 2), &1));
+            assert!(black.contains(&((0, 0), &7)));
+            for (coord, value) in &black {
+                assert_eq!(grid.get(*coord), Some(*value));
+            }
+
+            let white = grid.iter_masked(|(x, y)| (x + y).rem_euclid(2) == 1);
+            assert_eq!(white.count(), 8);
+        }
+
+        #[test]
+        fn should_yield_nothing_or_everything() {
+            let grid = wrap_grid(false, false);
+            assert_eq!(grid.iter_masked(|_| false).count(), 0);
+            let all: Vec<_> = grid.iter_masked(|_| true).map(|(_, v)| *v).collect();
+            assert_eq!(all, grid.iter().copied().collect::<Vec<_>>());
+        }
+    }
+
+    mod parity_iter {
+        use super::*;
+
+        #[test]
+        fn should_partition_cells() {
+            let grid = Grid::new_from_1d((0..16).collect(), 4, 4, None).unwrap();
+            let even: Vec<_> = grid.parity_iter(true).map(|(_, v)| *v).collect();
+            let odd: Vec<_> = grid.parity_iter(false).map(|(_, v)| *v).collect();
+            assert_eq!(even, vec![0, 2, 5, 7, 8, 10, 13, 15]);
+            assert_eq!(odd, vec![1, 3, 4, 6, 9, 11, 12, 14]);
+
+            let mut all: Vec<_> = even.iter().chain(&odd).copied().collect();
+            all.sort_unstable();
+            assert_eq!(all, (0..16).collect::<Vec<_>>());
+        }
+
+        #[test]
+        fn should_yield_coordinates() {
+            let grid = center_grid();
+            for even in [true, false] {
+                for (coord, value) in grid.parity_iter(even) {
+                    assert_eq!(grid.get(coord), Some(value));
+                }
+            }
+            assert_eq!(grid.parity_iter(true).count(), 8);
+            assert_eq!(grid.parity_iter(false).count(), 7);
+            assert_eq!(grid.parity_iter(true).next(), Some(((-1, 2), &0)));
+        }
+    }
+
+    mod coordinates {
+        use super::*;
+
+        #[test]
+        fn should_iter_center_coordinates() {
+            let grid = center_grid();
+            let coords: Vec<_> = grid.coordinates().collect();
+            assert_eq!(coords.len(), grid.size());
+            assert_eq!(coords.first(), Some(&(-1, 2)));
+            assert_eq!(coords.last(), Some(&(1, -2)));
+            assert_eq!(coords[7], (0, 0));
+            for (coord, value) in grid.coordinates().zip(grid.iter()) {
+                assert_eq!(grid.get(coord), Some(value));
+            }
+        }
+    }
+
+    mod for_each_coord_mut {
+        use super::*;
+
+        #[test]
+        fn should_pass_lower_left_coordinates() {
+            let vec = vec![vec![0, 1], vec![2, 3], vec![4, 5]];
+            let gridoptions = GridOptions {
+                origin: Origin::LowerLeft,
+                inverted_y: false,
+                ..GridOptions::default()
+            };
+            let mut grid = Grid::new(vec, Some(gridoptions)).unwrap();
+
+            let mut visited = vec![];
+            grid.for_each_coord_mut(|coord, cell| {
+                visited.push(coord);
+                *cell = coord.0 * 10 + coord.1;
+            });
+            assert_eq!(
+                visited,
+                vec![(0, 2), (1, 2), (0, 1), (1, 1), (0, 0), (1, 0)]
+            );
+            assert_eq!(
+                grid.to_2d_vec(),
+                vec![vec![2, 12], vec![1, 11], vec![0, 10]]
+            );
+            assert_eq!(grid.get((1, 0)), Some(&10));
+            assert_eq!(grid.get((0, 2)), Some(&2));
+        }
+
+        #[test]
+        fn should_pass_center_coordinates() {
+            let original = center_grid();
+            let mut grid = center_grid();
+            grid.for_each_coord_mut(|(x, y), cell| *cell += (x * 100 + y) as i32);
+            for (x, y) in [(0, 0), (-1, 2), (1, -2), (1, 1)] {
+                let expected = original.get((x, y)).unwrap() + (x * 100 + y) as i32;
+                assert_eq!(grid.get((x, y)), Some(&expected));
+            }
+        }
+    }
+
+    mod neighbor_count {
+        use super::*;
+
+        #[test]
+        fn should_count_glider_neighbors() {
+            let glider = vec![
+                vec![0, 1, 0, 0, 0],
+                vec![0, 0, 1, 1, 0],
+                vec![0, 1, 1, 0, 0],
+                vec![0, 0, 0, 0, 0],
+                vec![0, 0, 0, 0, 0],
+            ];
+            let gridoptions = GridOptions {
+                wrap_x: true,
+                wrap_y: true,
+                ..GridOptions::default()
+            };
+            let grid = Grid::new(glider, Some(gridoptions)).unwrap();
+            let counts: Vec<_> = (0..grid.size())
+                .map(|i| grid.neighbor_count(i, true, |c| *c == 1).unwrap())
+                .collect();
+            #[rustfmt::skip]
+            let expected = vec![
+                1, 1, 3, 2, 1,
+                2, 4, 4, 2, 1,
+                1, 2, 3, 3, 1,
+                1, 2, 2, 1, 0,
+                1, 1, 1, 0, 0,
+            ];
+            assert_eq!(counts, expected);
+
+            let counts: Vec<_> = (0..grid.size())
+                .map(|i| grid.count_neighbors_eq(i, &1, true).unwrap())
+                .collect();
+            assert_eq!(counts, expected);
+
+            // Each count matches the example's filtering of all around neighbors
+            for i in 0..grid.size() {
+                let neighbors = grid.all_around_neighbors(i).unwrap();
+                let alive = neighbors.iter().filter(|n| **n == Some(&1)).count();
+                assert_eq!(grid.neighbor_count(i, true, |c| *c == 1).unwrap(), alive);
+            }
+        }
+
+        #[test]
+        fn should_count_equal_neighbors() {
+            let grid = wrap_grid(false, false);
+            assert_eq!(grid.count_neighbors_eq((1, 2), &4, false).unwrap(), 1);
+            assert_eq!(grid.count_neighbors_eq((1, 2), &3, false).unwrap(), 0);
+            assert_eq!(grid.count_neighbors_eq((1, 2), &3, true).unwrap(), 1);
+            assert_eq!(grid.count_neighbors_eq((1, 2), &7, true).unwrap(), 0);
+            assert!(matches!(
+                grid.count_neighbors_eq((3, 0), &0, true),
+                Err(GridError::IndexOutOfBounds { .. })
+            ));
+        }
+
+        #[test]
+        fn should_count_orthogonal_neighbors() {
+            let grid = wrap_grid(false, false);
+            assert_eq!(grid.neighbor_count((0, 0), false, |_| true).unwrap(), 2);
+            assert_eq!(grid.neighbor_count((1, 2), false, |_| true).unwrap(), 4);
+            assert_eq!(grid.neighbor_count((1, 2), true, |_| true).unwrap(), 8);
+            assert_eq!(
+                grid.neighbor_count((1, 2), true, |c| c % 2 == 0).unwrap(),
+                4
+            );
+
+            let grid = wrap_grid(true, true);
+            assert_eq!(grid.neighbor_count((0, 0), false, |_| true).unwrap(), 4);
+            assert_eq!(grid.neighbor_count((0, 0), true, |_| true).unwrap(), 8);
+        }
+
+        #[test]
+        fn should_err_on_invalid_index() {
+            let grid = center_grid();
+            assert!(matches!(
+                grid.neighbor_count((2, 0), true, |_| true),
+                Err(GridError::IndexOutOfBounds { .. })
+            ));
+        }
+    }
+
+    mod clamped {
+        use super::*;
+
+        #[test]
+        fn should_clamp_at_edges() {
+            let grid = wrap_grid(false, false);
+            // Corners
+            assert_eq!(grid.get_up_clamped((0, 0)), &0);
+            assert_eq!(grid.get_left_clamped((0, 0)), &0);
+            assert_eq!(grid.get_upleft_clamped((0, 0)), &0);
+            assert_eq!(grid.get_upright_clamped((0, 0)), &0);
+            assert_eq!(grid.get_downleft_clamped((0, 0)), &0);
+            assert_eq!(grid.get_down_clamped((2, 4)), &14);
+            assert_eq!(grid.get_right_clamped((2, 4)), &14);
+            assert_eq!(grid.get_downright_clamped((2, 4)), &14);
+            // Edges
+            assert_eq!(grid.get_left_clamped((0, 2)), &6);
+            assert_eq!(grid.get_right_clamped((2, 2)), &8);
+            assert_eq!(grid.get_up_clamped((1, 0)), &1);
+            assert_eq!(grid.get_down_clamped((1, 4)), &13);
+        }
+
+        #[test]
+        fn should_return_existing_neighbors() {
+            let grid = wrap_grid(false, false);
+            assert_eq!(grid.get_up_clamped((1, 2)), &4);
+            assert_eq!(grid.get_down_clamped((1, 2)), &10);
+            assert_eq!(grid.get_left_clamped((1, 2)), &6);
+            assert_eq!(grid.get_right_clamped((1, 2)), &8);
+            assert_eq!(grid.get_upleft_clamped((1, 2)), &3);
+            assert_eq!(grid.get_upright_clamped((1, 2)), &5);
+            assert_eq!(grid.get_downleft_clamped((1, 2)), &9);
+            assert_eq!(grid.get_downright_clamped((1, 2)), &11);
+        }
+
+        #[test]
+        fn should_wrap_instead_of_clamping() {
+            let grid = wrap_grid(true, true);
+            assert_eq!(grid.get_up_clamped((0, 0)), &12);
+            assert_eq!(grid.get_left_clamped((0, 0)), &2);
+        }
+
+        #[test]
+        #[should_panic]
+        fn should_panic_on_invalid_index() {
+            let grid = center_grid();
+            grid.get_up_clamped((2, 0));
+        }
+    }
+
+    mod ray {
+        use super::*;
+
+        #[test]
+        fn should_cast_ray_along_row() {
+            let grid = wrap_grid(false, false);
+            let ray: Vec<_> = grid.ray((0, 2), 1, 0).unwrap().copied().collect();
+            let row: Vec<_> = grid.iter_row(2).skip(1).copied().collect();
+            assert_eq!(ray, row);
+            assert_eq!(ray, vec![7, 8]);
+
+            let ray: Vec<_> = grid.ray((1, 4), 0, -2).unwrap().copied().collect();
+            assert_eq!(ray, vec![7, 1]);
+            assert_eq!(grid.ray((2, 2), 1, 0).unwrap().count(), 0);
+            assert!(grid.ray((3, 2), 1, 0).is_err());
+        }
+
+        #[test]
+        fn should_cast_diagonal_ray() {
+            let grid = wrap_grid(false, false);
+            let ray: Vec<_> = grid.ray((0, 0), 1, 1).unwrap().copied().collect();
+            assert_eq!(ray, vec![4, 8]);
+
+            let grid = center_grid();
+            let ray: Vec<_> = grid.ray((-1, -2), 1, 1).unwrap().copied().collect();
+            assert_eq!(ray, vec![10, 8]);
+        }
+
+        #[test]
+        fn should_stop_before_returning_to_start_when_wrapping() {
+            let grid = wrap_grid(true, false);
+            let ray: Vec<_> = grid.ray((1, 2), 1, 0).unwrap().copied().collect();
+            assert_eq!(ray, vec![8, 6]);
+
+            let grid = wrap_grid(true, true);
+            let ray: Vec<_> = grid.ray((0, 0), 1, 1).unwrap().copied().collect();
+            assert_eq!(ray.len(), 14);
+            assert_eq!(&ray[..4], &[4, 8, 9, 13]);
+            assert_eq!(grid.ray((0, 0), 0, 0).unwrap().count(), 0);
+        }
+    }
+
+    mod diagonals {
+        use super::*;
+
+        #[test]
+        fn should_group_diagonals() {
+            let grid = Grid::new_from_1d((0..9).collect(), 3, 3, None).unwrap();
+            let diagonals: Vec<_> = grid.diagonals().collect();
+            assert_eq!(
+                diagonals,
+                vec![
+                    vec![&6],
+                    vec![&3, &7],
+                    vec![&0, &4, &8],
+                    vec![&1, &5],
+                    vec![&2],
+                ]
+            );
+
+            let diagonals: Vec<_> = grid.anti_diagonals().collect();
+            assert_eq!(
+                diagonals,
+                vec![
+                    vec![&0],
+                    vec![&1, &3],
+                    vec![&2, &4, &6],
+                    vec![&5, &7],
+                    vec![&8],
+                ]
+            );
+        }
+
+        #[test]
+        fn should_cover_every_cell_once() {
+            let grid = center_grid();
+            let mut diagonals: Vec<_> = grid.diagonals().flatten().copied().collect();
+            assert_eq!(grid.diagonals().count(), 7);
+            diagonals.sort_unstable();
+            assert_eq!(diagonals, (0..15).collect::<Vec<_>>());
+
+            let mut anti: Vec<_> = grid.anti_diagonals().flatten().copied().collect();
+            assert_eq!(grid.anti_diagonals().count(), 7);
+            anti.sort_unstable();
+            assert_eq!(anti, (0..15).collect::<Vec<_>>());
+
+            let diagonals: Vec<_> = grid.diagonals().collect();
+            assert_eq!(diagonals[0], vec![&12]);
+            assert_eq!(diagonals[3], vec![&3, &7, &11]);
+            assert_eq!(diagonals[6], vec![&2]);
+            let anti: Vec<_> = grid.anti_diagonals().collect();
+            assert_eq!(anti[3], vec![&5, &7, &9]);
+        }
+
+        #[test]
+        fn should_handle_single_row_or_column() {
+            let grid = Grid::new(vec![vec![0, 1, 2]], None).unwrap();
+            assert_eq!(
+                grid.diagonals().collect::<Vec<_>>(),
+                vec![vec![&0], vec![&1], vec![&2]]
+            );
+            let grid = Grid::new(vec![vec![0], vec![1]], None).unwrap();
+            assert_eq!(
+                grid.anti_diagonals().collect::<Vec<_>>(),
+                vec![vec![&0], vec![&1]]
+            );
+        }
+    }
+
+    mod diagonal_iter {
+        use super::*;
+
+        #[test]
+        fn should_iter_from_corner() {
+            let grid = wrap_grid(false, false);
+            let diagonal: Vec<_> = grid.diagonal_iter((0, 0), true).collect();
+            assert_eq!(diagonal, vec![&0, &4, &8]);
+            let diagonal: Vec<_> = grid.diagonal_iter((2, 0), false).collect();
+            assert_eq!(diagonal, vec![&2, &4, &6]);
+            let diagonal: Vec<_> = grid.diagonal_iter((0, 0), false).collect();
+            assert_eq!(diagonal, vec![&0]);
+        }
+
+        #[test]
+        fn should_iter_from_center() {
+            let grid = wrap_grid(false, false);
+            let diagonal: Vec<_> = grid.diagonal_iter((1, 2), true).collect();
+            assert_eq!(diagonal, vec![&7, &11]);
+            let diagonal: Vec<_> = grid.diagonal_iter((1, 2), false).collect();
+            assert_eq!(diagonal, vec![&7, &9]);
+        }
+
+        #[test]
+        fn should_wrap_until_start() {
+            let grid = wrap_grid(true, true);
+            let diagonal: Vec<_> = grid.diagonal_iter((0, 0), true).collect();
+            assert_eq!(diagonal.len(), 15);
+            assert_eq!(&diagonal[..4], &[&0, &4, &8, &9]);
+
+            let grid = wrap_grid(true, false);
+            let diagonal: Vec<_> = grid.diagonal_iter((0, 0), false).collect();
+            assert_eq!(diagonal, vec![&0, &5, &7, &9, &14]);
+        }
+
+        #[test]
+        fn should_be_empty_for_invalid_index() {
+            let grid = center_grid();
+            assert_eq!(grid.diagonal_iter((2, 0), true).next(), None);
+        }
+    }
+
+    mod neighbors_iter {
+        use super::*;
+
+        #[test]
+        fn should_skip_missing_neighbors_at_corner() {
+            let grid = wrap_grid(false, false);
+            let neighbors: Vec<_> = grid.neighbors_iter((0, 0), false).unwrap().collect();
+            assert_eq!(neighbors, vec![&1, &3]);
+            let neighbors: Vec<_> = grid.neighbors_iter((2, 4), true).unwrap().collect();
+            assert_eq!(neighbors, vec![&11, &13, &10]);
+        }
+
+        #[test]
+        fn should_yield_all_neighbors_at_center() {
+            let grid = wrap_grid(false, false);
+            let neighbors: Vec<_> = grid.neighbors_iter((1, 2), false).unwrap().collect();
+            assert_eq!(neighbors, vec![&4, &6, &8, &10]);
+            let neighbors: Vec<_> = grid.neighbors_iter((1, 2), true).unwrap().collect();
+            assert_eq!(neighbors, vec![&4, &6, &8, &10, &3, &5, &9, &11]);
+        }
+
+        #[test]
+        fn should_wrap_neighbors() {
+            let grid = wrap_grid(true, true);
+            let neighbors: Vec<_> = grid.neighbors_iter((0, 0), true).unwrap().collect();
+            assert_eq!(neighbors, vec![&12, &2, &1, &3, &14, &13, &5, &4]);
+        }
+
+        #[test]
+        fn should_err_on_invalid_index() {
+            let grid = center_grid();
+            assert!(matches!(
+                grid.neighbors_iter((0, 3), false),
+                Err(GridError::IndexOutOfBounds { .. })
+            ));
+        }
+    }
+
+    mod neighbor_coords_iter {
+        use super::*;
+
+        #[test]
+        fn should_yield_neighbor_coords() {
+            let grid = wrap_grid(false, false);
+            let coords: Vec<_> = grid.neighbor_coords_iter((1, 1), true).unwrap().collect();
+            assert_eq!(
+                coords,
+                vec![
+                    (1, 0),
+                    (0, 1),
+                    (2, 1),
+                    (1, 2),
+                    (0, 0),
+                    (2, 0),
+                    (0, 2),
+                    (2, 2)
+                ]
+            );
+            let coords: Vec<_> = grid.neighbor_coords_iter((2, 4), false).unwrap().collect();
+            assert_eq!(coords, vec![(2, 3), (1, 4)]);
+            assert!(grid.neighbor_coords_iter((3, 0), false).is_err());
+        }
+
+        #[test]
+        fn should_wrap_neighbor_coords() {
+            let grid = wrap_grid(true, true);
+            let coords: Vec<_> = grid.neighbor_coords_iter((0, 0), false).unwrap().collect();
+            assert_eq!(coords, vec![(0, 4), (2, 0), (1, 0), (0, 1)]);
+        }
+
+        #[test]
+        fn should_match_neighbors_iter() {
+            let grid = center_grid();
+            for coord in grid.coordinates() {
+                let values: Vec<_> = grid
+                    .neighbor_coords_iter(coord, true)
+                    .unwrap()
+                    .map(|c| grid.get(c).unwrap())
+                    .collect();
+                let expected: Vec<_> = grid.neighbors_iter(coord, true).unwrap().collect();
+                assert_eq!(values, expected);
+            }
+        }
+
+        #[test]
+        fn should_iterate_twice() {
+            let grid = center_grid();
+            let iter = grid.neighbor_coords_iter((0, 0), true).unwrap();
+            let first: Vec<_> = iter.clone().collect();
+            let second: Vec<_> = iter.collect();
+            assert_eq!(first.len(), 8);
+            assert_eq!(first, second);
+            let again: Vec<_> = grid.neighbor_coords_iter((0, 0), true).unwrap().collect();
+            assert_eq!(first, again);
+        }
+    }
+
+    mod neighbors8 {
+        use super::*;
+
+        fn four_column_grid(wrap: bool) -> Grid<i32> {
+            let gridoptions = GridOptions::default()
+                .with_neighbor_ybased(false)
+                .with_wrap(wrap, wrap);
+            Grid::new_from_1d((0..20).collect(), 4, 5, Some(gridoptions)).unwrap()
+        }
+
+        #[test]
+        fn should_return_neighbors_clockwise() {
+            let grid = four_column_grid(false);
+            let neighbors = grid.neighbors8((1, 1)).unwrap();
+            assert_eq!(
+                neighbors,
+                [
+                    Some(&1),
+                    Some(&2),
+                    Some(&6),
+                    Some(&10),
+                    Some(&9),
+                    Some(&8),
+                    Some(&4),
+                    Some(&0)
+                ]
+            );
+
+            let neighbors = grid.neighbors8((0, 1)).unwrap();
+            assert_eq!(
+                neighbors,
+                [
+                    Some(&0),
+                    Some(&1),
+                    Some(&5),
+                    Some(&9),
+                    Some(&8),
+                    None,
+                    None,
+                    None
+                ]
+            );
+        }
+
+        #[test]
+        fn should_match_all_around_neighbors() {
+            let grid = four_column_grid(true);
+            let neighbors = grid.neighbors8((0, 1)).unwrap();
+            assert_eq!(
+                neighbors,
+                [
+                    Some(&0),
+                    Some(&1),
+                    Some(&5),
+                    Some(&9),
+                    Some(&8),
+                    Some(&11),
+                    Some(&7),
+                    Some(&3)
+                ]
+            );
+
+            for index in 0..grid.size() {
+                let n = grid.all_around_neighbors(index).unwrap();
+                let expected = [
+                    n.up,
+                    n.upright,
+                    n.right,
+                    n.downright,
+                    n.down,
+                    n.downleft,
+                    n.left,
+                    n.upleft,
+                ];
+                assert_eq!(grid.neighbors8(index).unwrap(), expected);
+            }
+        }
+
+        #[test]
+        fn should_err_on_invalid_index() {
+            let grid = four_column_grid(false);
+            assert!(matches!(
+                grid.neighbors8((4, 0)),
+                Err(GridError::IndexOutOfBounds { .. })
+            ));
+        }
+    }
+
+    mod labeled_neighbors {
+        use super::*;
+
+        #[test]
+        fn should_label_center_neighbors() {
+            let grid = center_grid();
+            let neighbors = grid.labeled_neighbors((0, 0), true).unwrap();
+            assert_eq!(
+                neighbors,
+                vec![
+                    (Direction::Up, (0, 1), &4),
+                    (Direction::Left, (-1, 0), &6),
+                    (Direction::Right, (1, 0), &8),
+                    (Direction::Down, (0, -1), &10),
+                    (Direction::UpLeft, (-1, 1), &3),
+                    (Direction::UpRight, (1, 1), &5),
+                    (Direction::DownLeft, (-1, -1), &9),
+                    (Direction::DownRight, (1, -1), &11),
+                ]
+            );
+            for (_, coord, value) in neighbors {
+                assert_eq!(grid.get(coord), Some(value));
+            }
+
+            let neighbors = grid.labeled_neighbors((0, 0), false).unwrap();
+            assert_eq!(neighbors.len(), 4);
+        }
+
+        #[test]
+        fn should_label_edge_and_wrapped_neighbors() {
+            let grid = wrap_grid(false, false);
+            let neighbors = grid.labeled_neighbors((2, 0), true).unwrap();
+            let directions: Vec<_> = neighbors.iter().map(|n| n.0).collect();
+            assert_eq!(
+                directions,
+                vec![Direction::Left, Direction::Down, Direction::DownLeft]
+            );
 
-        Ok(())
+            let grid = wrap_grid(true, false);
+            let neighbors = grid.labeled_neighbors((2, 0), false).unwrap();
+            assert_eq!(
+                neighbors,
+                vec![
+                    (Direction::Left, (1, 0), &1),
+                    (Direction::Right, (0, 0), &0),
+                    (Direction::Down, (2, 1), &5),
+                ]
+            );
+        }
+
+        #[test]
+        fn should_err_on_invalid_index() {
+            let grid = center_grid();
+            assert!(matches!(
+                grid.labeled_neighbors((2, 0), false),
+                Err(GridError::IndexOutOfBounds { .. })
+            ));
+        }
     }
 
-    mod getters {
+    mod count {
         use super::*;
 
         #[test]
-        fn should_get_item() {
+        fn should_count_matching_cells() {
             let grid = center_grid();
-            assert_eq!(grid.get((0, 0)).unwrap(), &7i32);
-            assert_eq!(grid.get((-1, 1)).unwrap(), &3i32);
-            assert_eq!(grid.get(1).unwrap(), &1i32);
-            assert_eq!(grid.get((-2, 0)), None);
+            assert_eq!(grid.count(|v| *v > 10), 4);
+            assert_eq!(grid.count(|v| *v > 14), 0);
+            assert_eq!(grid.count(|_| true), grid.size());
         }
 
         #[test]
-        fn should_get_mut_item() {
-            let mut grid = center_grid();
-            let v = grid.get_mut((0, 0)).unwrap();
-            assert_eq!(*v, 7i32);
-            *v = 12i32;
-            assert_eq!(*v, 12i32);
-            let v = grid.get((0, 0)).unwrap();
-            assert_eq!(*v, 12i32);
+        fn should_count_equal_cells() {
+            let vec = vec![vec![1, 0, 1], vec![0, 1, 1], vec![2, 1, 0]];
+            let grid = Grid::new(vec, None).unwrap();
+            assert_eq!(grid.count_eq(&1), 5);
+            assert_eq!(grid.count_eq(&0), 3);
+            assert_eq!(grid.count_eq(&2), 1);
+            assert_eq!(grid.count_eq(&3), 0);
         }
 
         #[test]
-        fn should_get_up() {
-            let grid = center_grid();
-            assert_eq!(grid.get_up((0, 0)), Some(&4i32));
-            assert_eq!(grid.get_up((-1, 1)), Some(&0i32));
-            assert_eq!(grid.get_up(1), None);
-            assert_eq!(grid.get_up((-2, 0)), None);
+        fn should_tally_histogram() {
+            let vec = vec![vec![1, 0, 1], vec![0, 1, 1], vec![2, 1, 0]];
+            let grid = Grid::new(vec, None).unwrap();
+            let histogram = grid.histogram();
+            assert_eq!(histogram.len(), 3);
+            assert_eq!(histogram[&1], 5);
+            assert_eq!(histogram[&0], 3);
+            assert_eq!(histogram[&2], 1);
+            assert_eq!(histogram.get(&3), None);
+            assert_eq!(histogram.values().sum::<usize>(), grid.size());
         }
+    }
+
+    mod reductions {
+        use super::*;
 
         #[test]
-        fn should_get_down() {
+        fn should_sum_and_multiply() {
             let grid = center_grid();
-            assert_eq!(grid.get_down((0, 0)), Some(&10i32));
-            assert_eq!(grid.get_down((-1, 1)), Some(&6i32));
-            assert_eq!(grid.get_down(12), None);
-            assert_eq!(grid.get_down((-2, 0)), None);
+            assert_eq!(grid.sum(), 105);
+            assert_eq!(grid.product(), 0);
+
+            let grid = Grid::new(vec![vec![1.5, 2.0], vec![0.5, 4.0]], None).unwrap();
+            assert_eq!(grid.sum(), 8.0);
+            assert_eq!(grid.product(), 6.0);
         }
 
         #[test]
-        fn should_get_left() {
+        fn should_fold_into_max() {
             let grid = center_grid();
-            assert_eq!(grid.get_left((0, 0)), Some(&6i32));
-            assert_eq!(grid.get_left((1, 1)), Some(&4i32));
-            assert_eq!(grid.get_left(12), None);
-            assert_eq!(grid.get_left((-2, 0)), None);
+            assert_eq!(grid.fold(i32::MIN, |max, v| max.max(*v)), 14);
+            assert_eq!(grid.fold(0, |acc, v| acc + v), grid.sum());
+            assert_eq!(grid.fold(0usize, |acc, _| acc + 1), grid.size());
         }
+    }
+
+    mod windows {
+        use super::*;
 
         #[test]
-        fn should_get_right() {
+        fn should_slide_window() {
             let grid = center_grid();
-            assert_eq!(grid.get_right((0, 0)), Some(&8i32));
-            assert_eq!(grid.get_right((-1, -1)), Some(&10i32));
-            assert_eq!(grid.get_right(11), None);
-            assert_eq!(grid.get_right((-2, 0)), None);
+            let windows: Vec<_> = grid.windows(2, 2).collect();
+            assert_eq!(windows.len(), 8);
+            assert_eq!(windows[0], vec![&0, &1, &3, &4]);
+            assert_eq!(windows[1], vec![&1, &2, &4, &5]);
+            assert_eq!(windows[2], vec![&3, &4, &6, &7]);
+            assert_eq!(windows[7], vec![&10, &11, &13, &14]);
+
+            let windows: Vec<_> = grid.windows(3, 5).collect();
+            assert_eq!(windows.len(), 1);
+            assert_eq!(windows[0], grid.iter().collect::<Vec<_>>());
         }
 
         #[test]
-        fn should_get_up_wrap() {
-            let grid = wrap_grid(false, true);
-            assert_eq!(grid.get_up((0, 1)), Some(&0i32));
-            assert_eq!(grid.get_up((0, 0)), Some(&12i32));
-            assert_eq!(grid.get_up((0, 2)), Some(&3i32));
+        fn should_be_empty_when_window_too_large() {
+            let grid = center_grid();
+            assert_eq!(grid.windows(4, 2).count(), 0);
+            assert_eq!(grid.windows(2, 6).count(), 0);
+            assert_eq!(grid.windows(0, 2).count(), 0);
         }
+    }
+
+    mod boundary_iter {
+        use super::*;
 
         #[test]
-        fn should_get_down_wrap() {
-            let grid = wrap_grid(false, true);
-            assert_eq!(grid.get_down((0, 3)), Some(&12i32));
-            assert_eq!(grid.get_down((0, 4)), Some(&0i32));
-            assert_eq!(grid.get_down((0, 0)), Some(&3i32));
+        fn should_walk_perimeter() {
+            let grid = center_grid();
+            let boundary: Vec<_> = grid.boundary_iter().copied().collect();
+            assert_eq!(boundary, vec![0, 1, 2, 5, 8, 11, 14, 13, 12, 9, 6, 3]);
         }
 
         #[test]
-        fn should_get_left_wrap() {
-            let grid = wrap_grid(true, false);
-            assert_eq!(grid.get_left((1, 0)), Some(&0i32));
-            assert_eq!(grid.get_left((0, 0)), Some(&2i32));
-            assert_eq!(grid.get_left((2, 0)), Some(&1i32));
+        fn should_visit_single_row_or_column_once() {
+            let grid = Grid::new(vec![vec![0, 1, 2, 3]], None).unwrap();
+            let boundary: Vec<_> = grid.boundary_iter().copied().collect();
+            assert_eq!(boundary, vec![0, 1, 2, 3]);
+
+            let grid = Grid::new(vec![vec![0], vec![1], vec![2]], None).unwrap();
+            let boundary: Vec<_> = grid.boundary_iter().copied().collect();
+            assert_eq!(boundary, vec![0, 1, 2]);
+
+            let grid = Grid::new(vec![vec![0]], None).unwrap();
+            assert_eq!(grid.boundary_iter().count(), 1);
+
+            let grid = Grid::new(vec![vec![0, 1], vec![2, 3]], None).unwrap();
+            let boundary: Vec<_> = grid.boundary_iter().copied().collect();
+            assert_eq!(boundary, vec![0, 1, 3, 2]);
         }
+    }
+
+    mod interior_iter {
+        use super::*;
 
         #[test]
-        fn should_get_right_wrap() {
-            let grid = wrap_grid(true, false);
-            assert_eq!(grid.get_right((1, 0)), Some(&2i32));
-            assert_eq!(grid.get_right((2, 0)), Some(&0i32));
-            assert_eq!(grid.get_right((0, 0)), Some(&1i32));
+        fn should_skip_edge_cells() {
+            let grid = Grid::new_from_1d((0..20).collect(), 4, 5, None).unwrap();
+            let interior: Vec<_> = grid.interior_iter().copied().collect();
+            assert_eq!(interior, vec![5, 6, 9, 10, 13, 14]);
+
+            let grid = center_grid();
+            let interior: Vec<_> = grid.interior_iter().copied().collect();
+            assert_eq!(interior, vec![4, 7, 10]);
+            let boundary = grid.boundary_iter().count();
+            assert_eq!(interior.len() + boundary, grid.size());
         }
+
         #[test]
-        fn basic_quadrant() {
-            let vec = vec![vec![0, 1], vec![2, 3]];
+        fn should_be_empty_for_thin_grids() {
+            let grid = Grid::new_from_1d((0..10).collect(), 5, 2, None).unwrap();
+            assert_eq!(grid.interior_iter().count(), 0);
+            let grid = Grid::new_from_1d((0..10).collect(), 2, 5, None).unwrap();
+            assert_eq!(grid.interior_iter().count(), 0);
+            let grid = Grid::new(vec![vec![0]], None).unwrap();
+            assert_eq!(grid.interior_iter().count(), 0);
+        }
+    }
 
-            let grid = Grid::new(vec, None).unwrap();
-            assert_eq!(grid.nrant((0, 0), 1).unwrap(), 0);
-            assert_eq!(grid.nrant((1, 0), 1).unwrap(), 0);
-            assert_eq!(grid.nrant((0, 1), 1).unwrap(), 0);
-            assert_eq!(grid.nrant((1, 1), 1).unwrap(), 0);
+    mod sample {
+        use super::*;
 
-            assert_eq!(grid.nrant((0, 0), 2).unwrap(), 0);
-            assert_eq!(grid.nrant((1, 0), 2).unwrap(), 1);
-            assert_eq!(grid.nrant((0, 1), 2).unwrap(), 2);
-            assert_eq!(grid.nrant((1, 1), 2).unwrap(), 3);
+        #[test]
+        fn should_sample_every_other_cell() {
+            let grid = Grid::new_from_1d((0..16).collect(), 4, 4, None).unwrap();
+            let sampled: Vec<_> = grid.sample(2, 2).copied().collect();
+            assert_eq!(sampled, vec![0, 2, 8, 10]);
+
+            let sampled: Vec<_> = grid.sample(3, 1).copied().collect();
+            assert_eq!(sampled, vec![0, 3, 4, 7, 8, 11, 12, 15]);
+            assert_eq!(grid.sample(1, 1).count(), 16);
+            assert_eq!(grid.sample(5, 5).copied().collect::<Vec<_>>(), vec![0]);
         }
 
         #[test]
-        fn uneven_quadrant() {
-            let vec = vec![vec![0, 1, 2], vec![3, 4, 5]];
+        fn should_treat_zero_step_as_one() {
+            let grid = center_grid();
+            assert!(grid.sample(0, 1).eq(grid.iter()));
+            assert!(grid.sample(0, 0).eq(grid.iter()));
+            assert!(grid.sample(2, 0).eq(grid.sample(2, 1)));
+        }
+    }
 
-            let grid = Grid::new(vec, None).unwrap();
+    mod convolve {
+        use super::*;
 
-            assert_eq!(grid.nrant((0, 0), 2).unwrap(), 0);
-            assert_eq!(grid.nrant((1, 0), 2).unwrap(), 0);
-            assert_eq!(grid.nrant((2, 0), 2).unwrap(), 1);
-            assert_eq!(grid.nrant((0, 1), 2).unwrap(), 2);
-            assert_eq!(grid.nrant((1, 1), 2).unwrap(), 2);
-            assert_eq!(grid.nrant((2, 1), 2).unwrap(), 3);
+        #[test]
+        fn should_box_blur() {
+            let grid = Grid::new(vec![vec![9; 4]; 3], None).unwrap();
+            let blur = |window: &[&i32]| window.iter().copied().sum::<i32>() / 9;
+            let blurred = grid.convolve(3, 3, blur).unwrap();
+            assert_eq!(
+                blurred.to_2d_vec(),
+                vec![vec![4, 6, 6, 4], vec![6, 9, 9, 6], vec![4, 6, 6, 4]]
+            );
         }
 
         #[test]
-        fn nrant_start() {
-            let vec = vec![vec![0, 1], vec![2, 3]];
+        fn should_pass_window_in_row_major_order() {
+            let grid = center_grid()
+                .try_map(|v| Ok::<_, ()>(v.to_string()))
+                .unwrap();
+            let windows = grid
+                .convolve(3, 3, |window| {
+                    window
+                        .iter()
+                        .map(|v| v.as_str())
+                        .collect::<Vec<_>>()
+                        .join(",")
+                })
+                .unwrap();
+            assert_eq!(windows.get((0, 0)).unwrap(), "3,4,5,6,7,8,9,10,11");
+            assert_eq!(windows.get((-1, 2)).unwrap(), ",,,,0,1,,3,4");
+            assert_eq!(windows.options, grid.options);
+        }
 
-            let grid = Grid::new(vec, None).unwrap();
-            assert_eq!(grid.nrant_start(0, 1), 0);
-            assert_eq!(grid.nrant_start(1, 1), 0);
-            assert_eq!(grid.nrant_start(2, 1), 0);
-            assert_eq!(grid.nrant_start(3, 1), 0);
+        #[test]
+        fn should_err_on_even_window() {
+            let grid = center_grid();
+            assert!(matches!(
+                grid.convolve(2, 3, |w| *w[0]),
+                Err(GridError::InvalidSize)
+            ));
+            assert!(matches!(
+                grid.convolve(3, 0, |w| *w[0]),
+                Err(GridError::InvalidSize)
+            ));
+            let same = grid.convolve(1, 1, |w| *w[0]).unwrap();
+            assert_eq!(same, grid);
+        }
 
-            assert_eq!(grid.nrant_start(0, 2), 0);
-            assert_eq!(grid.nrant_start(1, 2), 1);
-            assert_eq!(grid.nrant_start(2, 2), 2);
-            assert_eq!(grid.nrant_start(3, 2), 3);
+        #[test]
+        fn should_err_on_excessive_window() {
+            let grid = center_grid();
+            let sum = |w: &[&i32]| w.iter().copied().sum();
+            for (width, height) in [(usize::MAX, 1), (1, usize::MAX), (9, 1), (1, 13)] {
+                assert!(
+                    matches!(
+                        grid.convolve(width, height, sum),
+                        Err(GridError::ExcessiveSize)
+                    ),
+                    "{width} x {height}"
+                );
+            }
+            // The largest window still reaches every cell from every other cell
+            let total: i32 = grid.iter().sum();
+            let largest = grid.convolve(7, 11, sum).unwrap();
+            assert!(largest.iter().all(|v| *v == total));
         }
+    }
+
+    mod tiles {
+        use super::*;
 
         #[test]
-        fn uneven_quadrant_start() {
-            let vec = vec![vec![0, 1, 2], vec![3, 4, 5]];
+        fn should_tile_into_blocks() {
+            let grid = Grid::new_from_1d((0..16).collect(), 4, 4, None).unwrap();
+            let tiles: Vec<_> = grid.tiles(2, 2).unwrap().collect();
+            assert_eq!(
+                tiles,
+                vec![
+                    vec![&0, &1, &4, &5],
+                    vec![&2, &3, &6, &7],
+                    vec![&8, &9, &12, &13],
+                    vec![&10, &11, &14, &15],
+                ]
+            );
 
-            let grid = Grid::new(vec, None).unwrap();
+            let tiles: Vec<_> = grid.tiles(4, 1).unwrap().collect();
+            assert_eq!(tiles.len(), 4);
+            assert_eq!(tiles[3], vec![&12, &13, &14, &15]);
+        }
 
-            assert_eq!(grid.nrant_start(0, 2), 0);
-            assert_eq!(grid.nrant_start(1, 2), 0);
-            assert_eq!(grid.nrant_start(2, 2), 2);
-            assert_eq!(grid.nrant_start(3, 2), 3);
-            assert_eq!(grid.nrant_start(4, 2), 3);
-            assert_eq!(grid.nrant_start(5, 2), 5);
+        #[test]
+        fn should_match_nrants_when_evenly_divisible() {
+            let grid = Grid::new_from_1d((0..81).collect(), 9, 9, None).unwrap();
+            for (i, tile) in grid.tiles(3, 3).unwrap().enumerate() {
+                let nrant: Vec<_> = grid
+                    .nrant_iter(3, (i % 3) * 3 + (i / 3) * 27)
+                    .flatten()
+                    .collect();
+                assert_eq!(tile, nrant);
+            }
+        }
+
+        #[test]
+        fn should_err_when_not_evenly_divisible() {
+            let grid = center_grid();
+            assert!(matches!(grid.tiles(2, 5), Err(GridError::InvalidSize)));
+            assert!(matches!(grid.tiles(3, 2), Err(GridError::InvalidSize)));
+            assert!(matches!(grid.tiles(0, 5), Err(GridError::InvalidSize)));
+            assert_eq!(grid.tiles(3, 5).unwrap().count(), 1);
         }
     }
 
-    mod row_iters {
+    mod downsample {
         use super::*;
 
         #[test]
-        fn should_return_none_outside_bounds() {
+        fn should_average_blocks() {
+            let grid = Grid::new_from_1d((0..16).collect(), 4, 4, None).unwrap();
+            let average = |block: &[&i32]| block.iter().copied().sum::<i32>() / block.len() as i32;
+            let small = grid.downsample(2, 2, average).unwrap();
+            assert_eq!(small.rows(), 2);
+            assert_eq!(small.columns(), 2);
+            assert_eq!(small.to_2d_vec(), vec![vec![2, 4], vec![10, 12]]);
+
+            let small = grid.downsample(4, 1, average).unwrap();
+            assert_eq!(small.to_2d_vec(), vec![vec![1], vec![5], vec![9], vec![13]]);
+        }
+
+        #[test]
+        fn should_keep_options() {
             let grid = center_grid();
-            let mut iter = grid.row_iter((2, 0));
-            assert_eq!(iter.next(), None);
-            assert_eq!(iter.next(), None);
+            let small = grid.downsample(3, 5, |block| block.len() as i32).unwrap();
+            assert_eq!(small.get((0, 0)), Some(&15));
+            assert!(
+                small.same_layout(&Grid::new(vec![vec![15]], Some(grid.options.clone())).unwrap())
+            );
         }
 
         #[test]
-        fn should_return_none_outside_bounds_mut() {
-            let mut grid = center_grid();
-            let mut iter = grid.row_iter_mut((2, 0));
-            assert_eq!(iter.next(), None);
-            assert_eq!(iter.next(), None);
+        fn should_err_when_not_evenly_divisible() {
+            let grid = center_grid();
+            assert!(matches!(
+                grid.downsample(2, 2, |block| *block[0]),
+                Err(GridError::InvalidSize)
+            ));
         }
+    }
+
+    mod xy_neighbors {
+        use super::*;
 
         #[test]
-        fn should_iter_mutably() {
-            let mut grid = center_grid();
-            for cell in grid.row_iter_mut((0, 1)) {
-                *cell += 1;
-            }
-            let mut iter = grid.row_iter((0, 1));
-            assert_eq!(iter.next(), Some(&4));
-            assert_eq!(iter.next(), Some(&5));
-            assert_eq!(iter.next(), Some(&6));
+        fn should_iterate_in_documented_direction_order() {
+            let vec = vec![vec![0, 1, 2], vec![3, 4, 5], vec![6, 7, 8]];
+            let gridoptions = GridOptions {
+                origin: Origin::LowerLeft,
+                inverted_y: false,
+                ..GridOptions::default()
+            };
+            let grid = Grid::new(vec, Some(gridoptions)).unwrap();
+            let (x, y) = (1, 1);
+            let neighbors = grid.xy_neighbors((x, y)).unwrap();
+            assert_eq!(neighbors.up, grid.get((x, y + 1)));
+            assert_eq!(neighbors.left, grid.get((x - 1, y)));
+            assert_eq!(neighbors.right, grid.get((x + 1, y)));
+            assert_eq!(neighbors.down, grid.get((x, y - 1)));
+
+            let mut iter = neighbors.iter();
+            assert_eq!(iter.next(), Some(&Some(&1)));
+            assert_eq!(iter.next(), Some(&Some(&3)));
+            assert_eq!(iter.next(), Some(&Some(&5)));
+            assert_eq!(iter.next(), Some(&Some(&7)));
             assert_eq!(iter.next(), None);
         }
+
+        #[test]
+        fn should_override_wrap_per_call() {
+            let grid = wrap_grid(false, false);
+            let unwrapped = grid.xy_neighbors_wrapped((0, 0), false, false).unwrap();
+            assert_eq!(unwrapped, grid.xy_neighbors((0, 0)).unwrap());
+            assert_eq!(unwrapped.up, None);
+            assert_eq!(unwrapped.left, None);
+
+            let wrapped = grid.xy_neighbors_wrapped((0, 0), true, true).unwrap();
+            assert_eq!(wrapped.up, Some(&12));
+            assert_eq!(wrapped.left, Some(&2));
+            assert_eq!(wrapped.right, Some(&1));
+            assert_eq!(wrapped.down, Some(&3));
+            assert_eq!(wrapped, wrap_grid(true, true).xy_neighbors((0, 0)).unwrap());
+
+            let x_only = grid.xy_neighbors_wrapped((0, 0), true, false).unwrap();
+            assert_eq!(x_only.up, None);
+            assert_eq!(x_only.left, Some(&2));
+
+            // The grid's own settings can also be turned off
+            let grid = wrap_grid(true, true);
+            let unwrapped = grid.xy_neighbors_wrapped((2, 4), false, false).unwrap();
+            assert_eq!(unwrapped.right, None);
+            assert_eq!(unwrapped.down, None);
+            assert_eq!(unwrapped.up, Some(&11));
+            assert_eq!(unwrapped.left, Some(&13));
+        }
+
+        #[test]
+        fn should_get_corner_neighbor_coords() {
+            let grid = wrap_grid(false, false);
+            let coords = grid.xy_neighbor_coords((0, 0)).unwrap();
+            assert_eq!(coords, [None, None, Some((1, 0)), Some((0, 1))]);
+
+            let coords = grid.xy_neighbor_coords((2, 4)).unwrap();
+            assert_eq!(coords, [Some((2, 3)), Some((1, 4)), None, None]);
+
+            assert!(matches!(
+                grid.xy_neighbor_coords((-1, 0)),
+                Err(GridError::IndexOutOfBounds { .. })
+            ));
+        }
+
+        #[test]
+        fn should_get_wrapped_corner_neighbor_coords() {
+            let grid = wrap_grid(true, true);
+            let coords = grid.xy_neighbor_coords((0, 0)).unwrap();
+            assert_eq!(
+                coords,
+                [Some((0, 4)), Some((2, 0)), Some((1, 0)), Some((0, 1))]
+            );
+
+            let coords = grid.xy_neighbor_coords((2, 4)).unwrap();
+            assert_eq!(
+                coords,
+                [Some((2, 3)), Some((1, 4)), Some((0, 4)), Some((2, 0))]
+            );
+        }
     }
 
-    mod col_iters {
+    mod rows_cols_iters {
         use super::*;
 
         #[test]
-        fn should_return_none_outside_bounds() {
+        fn should_iter_over_every_row() {
             let grid = center_grid();
-            let mut iter = grid.col_iter((-4, 0));
-            assert_eq!(iter.next(), None);
-            assert_eq!(iter.next(), None);
+            assert_eq!(grid.rows_iter().count(), grid.rows());
+            let mut rows = grid.rows_iter();
+            assert_eq!(rows.next().unwrap().collect::<Vec<_>>(), vec![&0, &1, &2]);
+            let flattened: Vec<_> = grid.rows_iter().flatten().collect();
+            assert_eq!(flattened, grid.iter().collect::<Vec<_>>());
         }
 
         #[test]
-        fn should_return_none_outside_bounds_mut() {
-            let mut grid = center_grid();
-            let mut iter = grid.col_iter_mut((-4, 0));
-            assert_eq!(iter.next(), None);
-            assert_eq!(iter.next(), None);
+        fn should_iter_over_every_col() {
+            let grid = center_grid();
+            assert_eq!(grid.cols_iter().count(), grid.columns());
+            let mut cols = grid.cols_iter();
+            assert_eq!(
+                cols.next().unwrap().collect::<Vec<_>>(),
+                vec![&0, &3, &6, &9, &12]
+            );
+            let flattened: Vec<_> = grid.cols_iter().flatten().copied().collect();
+            assert_eq!(
+                flattened,
+                vec![0, 3, 6, 9, 12, 1, 4, 7, 10, 13, 2, 5, 8, 11, 14]
+            );
         }
+    }
+
+    mod into_rows {
+        use super::*;
 
         #[test]
-        fn should_iter_mutably() {
-            let mut grid = center_grid();
-            for cell in grid.col_iter_mut((0, 1)) {
-                *cell += 1;
-            }
-            let mut iter = grid.col_iter((0, 1));
-            assert_eq!(iter.next(), Some(&2));
-            assert_eq!(iter.next(), Some(&5));
-            assert_eq!(iter.next(), Some(&8));
-            assert_eq!(iter.next(), Some(&11));
-            assert_eq!(iter.next(), Some(&14));
-            assert_eq!(iter.next(), None);
+        fn should_round_trip_2d_vec() {
+            let vec = vec![
+                vec![0, 1, 2],
+                vec![3, 4, 5],
+                vec![6, 7, 8],
+                vec![9, 10, 11],
+                vec![12, 13, 14],
+            ];
+            let grid = Grid::new(vec.clone(), None).unwrap();
+            let rows: Vec<_> = grid.into_rows().collect();
+            assert_eq!(rows, vec);
+        }
+
+        #[test]
+        fn should_round_trip_to_2d_vec() {
+            let vec = vec![
+                vec![0, 1, 2],
+                vec![3, 4, 5],
+                vec![6, 7, 8],
+                vec![9, 10, 11],
+                vec![12, 13, 14],
+            ];
+            let grid = Grid::new(vec.clone(), None).unwrap();
+            assert_eq!(grid.to_2d_vec(), vec);
+            assert_eq!(center_grid().to_2d_vec(), vec);
+        }
+
+        #[test]
+        fn should_yield_one_vec_per_row() {
+            let grid = center_grid();
+            let mut rows = grid.into_rows();
+            assert_eq!(rows.next(), Some(vec![0, 1, 2]));
+            assert_eq!(rows.nth(3), Some(vec![12, 13, 14]));
+            assert_eq!(rows.next(), None);
         }
     }
+
     mod all_around_neighbors {
         use super::*;
 
@@ -1104,4 +6624,81 @@ mod grid_tests {
             assert_eq!(neighbors.downright, Some(&9));
         }
     }
+
+    mod neighborhood_3x3 {
+        use super::*;
+
+        #[test]
+        fn should_place_none_off_the_edges() {
+            let grid = wrap_grid(false, false);
+            let neighborhood = grid.neighborhood_3x3((0, 0)).unwrap();
+            assert_eq!(
+                neighborhood.to_2d_vec(),
+                vec![
+                    vec![None, None, None],
+                    vec![None, Some(&0), Some(&1)],
+                    vec![None, Some(&3), Some(&4)],
+                ]
+            );
+            assert_eq!(neighborhood.get((0, 0)), Some(&Some(&0)));
+
+            let neighborhood = grid.neighborhood_3x3((2, 4)).unwrap();
+            assert_eq!(
+                neighborhood.to_2d_vec(),
+                vec![
+                    vec![Some(&10), Some(&11), None],
+                    vec![Some(&13), Some(&14), None],
+                    vec![None, None, None],
+                ]
+            );
+        }
+
+        #[test]
+        fn should_wrap() {
+            let grid = wrap_grid(true, true);
+            let neighborhood = grid.neighborhood_3x3((0, 0)).unwrap();
+            assert_eq!(
+                neighborhood.to_2d_vec(),
+                vec![
+                    vec![Some(&14), Some(&12), Some(&13)],
+                    vec![Some(&2), Some(&0), Some(&1)],
+                    vec![Some(&5), Some(&3), Some(&4)],
+                ]
+            );
+
+            let grid = wrap_grid(true, false);
+            let neighborhood = grid.neighborhood_3x3((0, 0)).unwrap();
+            assert_eq!(
+                neighborhood.row_iter(1).copied().collect::<Vec<_>>(),
+                vec![None, None, None]
+            );
+        }
+
+        #[test]
+        fn should_match_all_around_neighbors() {
+            let grid = center_grid();
+            let neighborhood = grid.neighborhood_3x3((0, 0)).unwrap();
+            let neighbors = grid.all_around_neighbors((0, 0)).unwrap();
+            for direction in Direction::CLOCKWISE {
+                let (dx, dy) = match direction {
+                    Direction::Up => (0, 1),
+                    Direction::UpRight => (1, 1),
+                    Direction::Right => (1, 0),
+                    Direction::DownRight => (1, -1),
+                    Direction::Down => (0, -1),
+                    Direction::DownLeft => (-1, -1),
+                    Direction::Left => (-1, 0),
+                    Direction::UpLeft => (-1, 1),
+                };
+                assert_eq!(
+                    neighborhood.get((dx, dy)).copied().flatten(),
+                    neighbors.get(direction)
+                );
+            }
+            assert!(matches!(
+                grid.neighborhood_3x3((2, 0)),
+                Err(GridError::IndexOutOfBounds { x: 2, y: 0 })
+            ));
+        }
+    }
 }
@@ -1,11 +1,41 @@
 use crate::error::GridError;
 use crate::grid::{Grid, Origin};
+use std::ops::{Add, Sub};
 
-pub trait Index {
+/// A type that can refer to a cell of a grid, such as a coordinate or the internal vec index.  All the `Grid` methods that take a cell accept any
+/// `GridIndex`, including references to one.
+pub trait GridIndex {
     fn grid_index<T>(self, grid: &Grid<T>) -> Result<usize, GridError>;
+
+    /// The internal (column, row) position, zero based from the upper left of the grid, after wrapping positions outside the grid back into it on
+    /// both axes.  Used by `Grid::wrapping_get`.  The default implementation only supports positions inside the grid, and returns the error from
+    /// `grid_index` otherwise, so `wrapping_get` panics for such a type outside the grid.  Returns `EmptyGrid` if the grid has no cells.
+    fn wrapped_position<T>(self, grid: &Grid<T>) -> Result<(usize, usize), GridError>
+    where
+        Self: Sized,
+    {
+        let index = self.grid_index(grid)?;
+        Ok((index % grid.cols, index / grid.cols))
+    }
+}
+
+/// A `GridIndex` that can also be created from the internal vec index of a cell, such as when a method returns the location of a cell.  Only owned
+/// types implement it, as references cannot be created from an index.
+pub trait Index: GridIndex {
     fn output<T>(index: usize, grid: &Grid<T>) -> Self;
 }
 
+/// Allows indexing with a reference, such as a `&Coordinates` that is still needed after the call.
+impl<S: GridIndex + Clone> GridIndex for &S {
+    fn grid_index<T>(self, grid: &Grid<T>) -> Result<usize, GridError> {
+        self.clone().grid_index(grid)
+    }
+
+    fn wrapped_position<T>(self, grid: &Grid<T>) -> Result<(usize, usize), GridError> {
+        self.clone().wrapped_position(grid)
+    }
+}
+
 /// A stronger-typed way of expressing coordinates than just a tuple of `(isize, isize)`
 #[derive(Clone, Debug, PartialEq)]
 pub struct Coordinates {
@@ -13,50 +43,161 @@ pub struct Coordinates {
     pub y: isize,
 }
 
-impl Index for Coordinates {
+impl Coordinates {
+    /// Creates a coordinate from its `x` and `y` values
+    /// ```
+    /// use neighborgrid::*;
+    /// let coord = Coordinates::new(1, 2) + Coordinates::new(0, 1);
+    /// assert_eq!(coord, Coordinates { x: 1, y: 3 });
+    /// ```
+    pub fn new(x: isize, y: isize) -> Self {
+        Coordinates { x, y }
+    }
+}
+
+/// Converts a coordinate of `i32`s, returning `ExcessiveSize` if either value does not fit in an `isize`
+/// ```
+/// use neighborgrid::*;
+/// let point: (i32, i32) = (1, 2);
+/// assert_eq!(Coordinates::try_from(point).expect("does not fit"), Coordinates::new(1, 2));
+/// ```
+impl TryFrom<(i32, i32)> for Coordinates {
+    type Error = GridError;
+
+    fn try_from((x, y): (i32, i32)) -> Result<Self, GridError> {
+        let (x, y) = to_isize_pair(x, y)?;
+        Ok(Coordinates::new(x, y))
+    }
+}
+
+/// Converts a coordinate of `i64`s, returning `ExcessiveSize` if either value does not fit in an `isize`
+impl TryFrom<(i64, i64)> for Coordinates {
+    type Error = GridError;
+
+    fn try_from((x, y): (i64, i64)) -> Result<Self, GridError> {
+        let (x, y) = to_isize_pair(x, y)?;
+        Ok(Coordinates::new(x, y))
+    }
+}
+
+/// Adds the `x` and `y` values separately, such as for offsetting a coordinate to one of its neighbors.  This is independent of any grid, so the
+/// result may not be in a grid, and the direction of `y` depends on the grid's `GridOptions`.
+impl Add for Coordinates {
+    type Output = Coordinates;
+
+    fn add(self, rhs: Coordinates) -> Coordinates {
+        Coordinates::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+/// Subtracts the `x` and `y` values separately, such as for finding the offset from one coordinate to another.
+impl Sub for Coordinates {
+    type Output = Coordinates;
+
+    fn sub(self, rhs: Coordinates) -> Coordinates {
+        Coordinates::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl GridIndex for Coordinates {
     fn grid_index<T>(self, grid: &Grid<T>) -> Result<usize, GridError> {
         let y = invert_y(grid, self.y);
         bounds_check(grid, self.x, y)?;
         Ok(xy_to_index(grid, self.x, y))
     }
 
+    fn wrapped_position<T>(self, grid: &Grid<T>) -> Result<(usize, usize), GridError> {
+        wrap_xy(grid, self.x, self.y)
+    }
+}
+
+impl Index for Coordinates {
     fn output<T>(index: usize, grid: &Grid<T>) -> Self {
         let (x, y) = (index % grid.cols, index / grid.cols);
-        let (x, y) = adjust_to_origin(grid, x as isize, y as isize);
-        let y = invert_y(grid, y);
+        let (x, y) = internal_to_xy(grid, x as isize, y as isize);
         Coordinates { x, y }
     }
 }
 
-impl Index for usize {
+impl GridIndex for usize {
     fn grid_index<T>(self, grid: &Grid<T>) -> Result<usize, GridError> {
         if self < grid.size() {
             Ok(self)
+        } else if grid.is_empty() {
+            Err(GridError::EmptyGrid)
         } else {
-            Err(GridError::IndexOutOfBounds)
+            // The row may not fit in an isize, so saturate rather than wrap into a row inside the grid
+            let row = isize::try_from(self / grid.cols).unwrap_or(isize::MAX);
+            Err(out_of_bounds(grid, (self % grid.cols) as isize, row))
+        }
+    }
+
+    fn wrapped_position<T>(self, grid: &Grid<T>) -> Result<(usize, usize), GridError> {
+        if grid.is_empty() {
+            return Err(GridError::EmptyGrid);
         }
+        Ok((self % grid.cols, self / grid.cols % grid.rows))
     }
+}
 
+impl Index for usize {
     fn output<T>(index: usize, _grid: &Grid<T>) -> Self {
         index
     }
 }
 
-impl Index for (isize, isize) {
+impl GridIndex for (isize, isize) {
     fn grid_index<T>(self, grid: &Grid<T>) -> Result<usize, GridError> {
         let y = invert_y(grid, self.1);
         bounds_check(grid, self.0, y)?;
         Ok(xy_to_index(grid, self.0, y))
     }
 
+    fn wrapped_position<T>(self, grid: &Grid<T>) -> Result<(usize, usize), GridError> {
+        wrap_xy(grid, self.0, self.1)
+    }
+}
+
+impl Index for (isize, isize) {
     fn output<T>(index: usize, grid: &Grid<T>) -> Self {
         let (x, y) = (index % grid.cols, index / grid.cols);
-        let (x, y) = adjust_to_origin(grid, x as isize, y as isize);
-        let y = invert_y(grid, y);
-        (x, y)
+        internal_to_xy(grid, x as isize, y as isize)
+    }
+}
+
+/// Converts a coordinate of another integer type, returning `ExcessiveSize` if it does not fit in an `isize`
+fn to_isize_pair<N: TryInto<isize>>(x: N, y: N) -> Result<(isize, isize), GridError> {
+    match (x.try_into(), y.try_into()) {
+        (Ok(x), Ok(y)) => Ok((x, y)),
+        _ => Err(GridError::ExcessiveSize),
     }
 }
 
+/// Wraps an (x, y) coordinate into the grid and converts it to the internal (column, row) position.  The coordinate is reduced before adjusting for
+/// the `Origin`, so that coordinates far outside the grid cannot overflow.
+fn wrap_xy<T>(grid: &Grid<T>, x: isize, y: isize) -> Result<(usize, usize), GridError> {
+    if grid.is_empty() {
+        return Err(GridError::EmptyGrid);
+    }
+    let (cols, rows) = (grid.cols as isize, grid.rows as isize);
+    let (col, row) =
+        adjust_from_origin(grid, x.rem_euclid(cols), invert_y(grid, y.rem_euclid(rows)));
+    Ok((col.rem_euclid(cols) as usize, row.rem_euclid(rows) as usize))
+}
+
+/// Converts an internal (column, row) position into a coordinate based on the `Origin` and other `GridOptions`.  The position does not need to be
+/// inside the grid, which allows reporting where an out of bounds access would have been.
+pub(crate) fn internal_to_xy<T>(grid: &Grid<T>, col: isize, row: isize) -> (isize, isize) {
+    let (x, y) = adjust_to_origin(grid, col, row);
+    (x, invert_y(grid, y))
+}
+
+/// Creates an `IndexOutOfBounds` error for the internal (column, row) position
+pub(crate) fn out_of_bounds<T>(grid: &Grid<T>, col: isize, row: isize) -> GridError {
+    let (x, y) = internal_to_xy(grid, col, row);
+    GridError::IndexOutOfBounds { x, y }
+}
+
 fn invert_y<T>(grid: &Grid<T>, y: isize) -> isize {
     let options = &grid.options;
     if options.inverted_y {
@@ -67,6 +208,9 @@ fn invert_y<T>(grid: &Grid<T>, y: isize) -> isize {
 }
 
 fn bounds_check<T>(grid: &Grid<T>, x: isize, y: isize) -> Result<(), GridError> {
+    if grid.is_empty() {
+        return Err(GridError::EmptyGrid);
+    }
     let max_x = grid.max_x();
     let min_x = grid.min_x();
     let max_y = grid.max_y();
@@ -75,7 +219,10 @@ fn bounds_check<T>(grid: &Grid<T>, x: isize, y: isize) -> Result<(), GridError>
     if x >= min_x && x <= max_x && y >= min_y && y <= max_y {
         Ok(())
     } else {
-        Err(GridError::IndexOutOfBounds)
+        Err(GridError::IndexOutOfBounds {
+            x,
+            y: invert_y(grid, y),
+        })
     }
 }
 
@@ -125,7 +272,7 @@ fn convert_upper_left<T>(_grid: &Grid<T>, x: isize, y: isize) -> (isize, isize)
 
 #[inline]
 fn convert_lower_left<T>(grid: &Grid<T>, x: isize, y: isize) -> (isize, isize) {
-    (x, (grid.rows - 1) as isize - y)
+    (x, grid.rows as isize - 1 - y)
 }
 
 #[cfg(test)]
@@ -237,15 +384,49 @@ mod index_tests {
         let grid = center_origin();
         dbg!(grid.max_x(), grid.max_y());
         let index = (2, 0).grid_index(&grid);
-        assert!(matches!(index, Err(GridError::IndexOutOfBounds)));
+        assert!(matches!(index, Err(GridError::IndexOutOfBounds { .. })));
 
         let index = Coordinates { x: -3, y: 0 }.grid_index(&grid);
-        assert!(matches!(index, Err(GridError::IndexOutOfBounds)));
+        assert!(matches!(index, Err(GridError::IndexOutOfBounds { .. })));
 
         let index = (1, 0).grid_index(&grid);
         assert!(matches!(index, Ok(x) if x == 8));
     }
 
+    #[test]
+    fn should_report_out_of_bounds_coordinate() {
+        let grid = center_origin();
+        let index = (2, -1).grid_index(&grid);
+        assert!(matches!(
+            index,
+            Err(GridError::IndexOutOfBounds { x: 2, y: -1 })
+        ));
+
+        // Flat indexes are reported where they would be if the grid was larger
+        let index = 16usize.grid_index(&grid);
+        assert!(matches!(
+            index,
+            Err(GridError::IndexOutOfBounds { x: 0, y: -3 })
+        ));
+
+        let mut grid = origin_grid(Origin::UpperLeft);
+        grid.options.inverted_y = true;
+        let index = (1, 4).grid_index(&grid);
+        assert!(matches!(
+            index,
+            Err(GridError::IndexOutOfBounds { x: 1, y: 4 })
+        ));
+        assert_eq!(
+            index.unwrap_err().to_string(),
+            "Index out of bounds at (1, 4)"
+        );
+        let index = 13usize.grid_index(&grid);
+        assert!(matches!(
+            index,
+            Err(GridError::IndexOutOfBounds { x: 1, y: 4 })
+        ));
+    }
+
     #[test]
     fn should_convert_index_upperleft() -> Result<()> {
         let mut grid = origin_grid(Origin::UpperLeft);
@@ -408,7 +589,108 @@ mod index_tests {
 
         assert_eq!(index, cord_index);
         let cord_index = Coordinates { x: -2, y: 2 }.grid_index(&grid);
-        assert!(matches!(cord_index, Err(GridError::IndexOutOfBounds)));
+        assert!(matches!(
+            cord_index,
+            Err(GridError::IndexOutOfBounds { .. })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn coordinates_try_from_sized_tuple() -> Result<()> {
+        let grid = center_origin();
+        let point: (i32, i32) = (-1, 2);
+        assert_eq!(Coordinates::try_from(point)?.grid_index(&grid)?, 0);
+        let point: (i64, i64) = (1, -2);
+        assert_eq!(Coordinates::try_from(point)?.grid_index(&grid)?, 14);
+        assert!(matches!(
+            Coordinates::try_from((2i32, 0i32))?.grid_index(&grid),
+            Err(GridError::IndexOutOfBounds { x: 2, y: 0 })
+        ));
+        if isize::BITS < 64 {
+            assert!(matches!(
+                Coordinates::try_from((i64::MAX, 0)),
+                Err(GridError::ExcessiveSize)
+            ));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn untyped_tuple_is_isize() {
+        let grid = center_origin();
+        let point = (1, 0);
+        assert_eq!(grid.get(point), Some(&8));
+        assert_eq!(point.0.abs(), 1);
+    }
+
+    #[test]
+    fn coordinates_arithmetic() {
+        let coord = Coordinates::new(3, -2);
+        assert_eq!(coord, Coordinates { x: 3, y: -2 });
+        assert_eq!(
+            coord.clone() + Coordinates::new(0, 1),
+            Coordinates::new(3, -1)
+        );
+        assert_eq!(
+            coord.clone() + Coordinates::new(-4, -4),
+            Coordinates::new(-1, -6)
+        );
+        assert_eq!(coord - Coordinates::new(1, -2), Coordinates::new(2, 0));
+    }
+
+    #[test]
+    fn coordinates_arithmetic_index() -> Result<()> {
+        let grid = center_origin();
+        let up = Coordinates::new(0, 0) + Coordinates::new(0, 1);
+        assert_eq!(up.grid_index(&grid)?, 4);
+        Ok(())
+    }
+
+    fn assert_output_round_trips<I: Index + Clone + std::fmt::Debug>(grid: &Grid<i32>) {
+        for index in 0..grid.size() {
+            let output: I = Index::output(index, grid);
+            assert_eq!(
+                output.clone().grid_index(grid).ok(),
+                Some(index),
+                "{output:?} did not round trip"
+            );
+        }
+    }
+
+    #[test]
+    fn output_round_trips() {
+        for grid in [
+            basic_grid(),
+            center_origin(),
+            origin_grid(Origin::LowerLeft),
+        ] {
+            assert_output_round_trips::<Coordinates>(&grid);
+            assert_output_round_trips::<usize>(&grid);
+            assert_output_round_trips::<(isize, isize)>(&grid);
+        }
+    }
+
+    #[test]
+    fn reference_index() -> Result<()> {
+        let mut grid = center_origin();
+        let coord = Coordinates::new(-1, 2);
+        assert_eq!((&coord).grid_index(&grid)?, 0);
+        assert_eq!(grid.get(&coord), Some(&0));
+        *grid.get_mut(&coord).unwrap() = 20;
+        assert_eq!(grid.get(coord.clone()), Some(&20));
+        let center = Coordinates::new(0, 0);
+        assert_eq!(grid.get_up(&center), Some(&4));
+        assert_eq!(grid.neighbor_count(&center, true, |v| *v > 7)?, 4);
+        assert_eq!(center, Coordinates { x: 0, y: 0 });
+
+        let outside = Coordinates::new(-2, 0);
+        assert!(matches!(
+            grid.get_result(&outside),
+            Err(GridError::IndexOutOfBounds { x: -2, y: 0 })
+        ));
+        assert_eq!((&outside).wrapped_position(&grid)?, (2, 2));
+        assert_eq!(outside.wrapped_position(&grid)?, (2, 2));
         Ok(())
     }
 
@@ -422,7 +704,38 @@ mod index_tests {
         assert_eq!(index, 11);
 
         let cord_index = 12usize.grid_index(&grid);
-        assert!(matches!(cord_index, Err(GridError::IndexOutOfBounds)));
+        assert!(matches!(
+            cord_index,
+            Err(GridError::IndexOutOfBounds { .. })
+        ));
         Ok(())
     }
+
+    #[test]
+    fn usize_index_out_of_bounds_stays_outside() {
+        let grid = Grid::new_from_1d(vec![0, 1, 2], 1, 3, None).unwrap();
+        let res = grid.get_result(usize::MAX);
+        assert!(
+            matches!(
+                res,
+                Err(GridError::IndexOutOfBounds {
+                    x: 0,
+                    y: isize::MAX
+                })
+            ),
+            "{:?}",
+            res
+        );
+
+        for origin in [Origin::Center, Origin::LowerLeft] {
+            let mut grid = grid.clone();
+            grid.options.origin = origin;
+            match usize::MAX.grid_index(&grid) {
+                Err(GridError::IndexOutOfBounds { x, y }) => {
+                    assert!(grid.get((x, y)).is_none(), "({}, {})", x, y)
+                }
+                res => panic!("{:?}", res),
+            }
+        }
+    }
 }
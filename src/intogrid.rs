@@ -11,7 +11,7 @@ impl<T> IntoGrid<T> for Vec<Vec<T>> {
         let rows = self.len();
         let cols;
         let total;
-        if let Some(first) = self.get(0) {
+        if let Some(first) = self.first() {
             cols = first.len();
 
             total = row_col_length_check(rows, cols)?;
@@ -23,9 +23,13 @@ impl<T> IntoGrid<T> for Vec<Vec<T>> {
         }
 
         let mut grid = Vec::with_capacity(total);
-        for mut row in self.into_iter() {
+        for (i, mut row) in self.into_iter().enumerate() {
             if row.len() != cols {
-                return Err(GridError::RowSizeMismatch);
+                return Err(GridError::RowSizeMismatch {
+                    row: i,
+                    expected: cols,
+                    actual: row.len(),
+                });
             }
             grid.append(&mut row);
         }
@@ -33,6 +37,28 @@ impl<T> IntoGrid<T> for Vec<Vec<T>> {
     }
 }
 
+/// Converts a fixed size 2-D array to a `Grid`.  The shape is known at compile time, so rows can never mismatch in size.
+/// ```
+/// use neighborgrid::*;
+/// let grid = Grid::new([[0, 1, 2], [3, 4, 5]], None).expect("Failed to create Grid");
+/// assert_eq!(grid.rows(), 2);
+/// assert_eq!(grid.columns(), 3);
+/// assert_eq!(grid.get((1, 1)), Some(&4));
+/// ```
+impl<T, const C: usize, const R: usize> IntoGrid<T> for [[T; C]; R] {
+    fn into_grid(self) -> Result<Grid<T>, GridError> {
+        let total = row_col_length_check(R, C)?;
+        if total == 0 {
+            return Err(GridError::InvalidSize);
+        }
+        let mut items = Vec::with_capacity(total);
+        for row in self {
+            items.extend(row);
+        }
+        Ok(Grid::create(items, R, C, None))
+    }
+}
+
 /// Impl for a tuple of `(&Vec<T>, usize)`, where the usize is the number of rows.
 /// The input vec is repeated for the number of rows.  
 /// For example, (vec![1, 2, 3], 4).into_grid() will result in a 12 cell grid, with 1, 2, 3, 4 repeated on each row
@@ -51,8 +77,95 @@ impl<T: Clone> IntoGrid<T> for (Vec<T>, usize) {
     }
 }
 
+/// The number of columns in a grid, used to distinguish a flat `Vec` of all the cells from a single row that is repeated.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Columns(pub usize);
+
+/// Impl for a tuple of `(Vec<T>, Columns)`, where the vec holds every cell of the grid in row major order.  The number of rows is inferred from the length
+/// of the vec, which must be evenly divisible by the number of columns.
+/// ```
+/// use neighborgrid::*;
+/// let grid = Grid::new((vec![0, 1, 2, 3, 4, 5], Columns(3)), None).expect("Failed to create Grid");
+/// assert_eq!(grid.rows(), 2);
+/// assert_eq!(grid.columns(), 3);
+/// assert_eq!(grid.get((0, 1)), Some(&3));
+/// ```
+impl<T> IntoGrid<T> for (Vec<T>, Columns) {
+    fn into_grid(self) -> Result<Grid<T>, GridError> {
+        let (items, Columns(cols)) = self;
+        if cols == 0 || items.is_empty() || items.len() % cols != 0 {
+            return Err(GridError::InvalidSize);
+        }
+        let rows = items.len() / cols;
+        row_col_length_check(rows, cols)?;
+        Ok(Grid::create(items, rows, cols, None))
+    }
+}
+
+/// The number of rows and columns in a grid, passed around together so the two cannot be swapped by mistake.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Dimensions {
+    pub rows: usize,
+    pub cols: usize,
+}
+
+impl Dimensions {
+    /// The number of cells in a grid of these dimensions, or `None` if that overflows a `usize`
+    pub fn total(&self) -> Option<usize> {
+        self.rows.checked_mul(self.cols)
+    }
+}
+
+/// Impl for a tuple of `(Vec<T>, Dimensions)`, where the vec holds every cell of the grid in row major order.  Returns `SizeMismatch` if the
+/// length of the vec does not match the dimensions.
+/// ```
+/// use neighborgrid::*;
+/// let grid = Grid::new((vec![0, 1, 2, 3, 4, 5], Dimensions { rows: 3, cols: 2 }), None).expect("Failed to create Grid");
+/// assert_eq!(grid.rows(), 3);
+/// assert_eq!(grid.columns(), 2);
+/// assert_eq!(grid.get((0, 1)), Some(&2));
+/// ```
+impl<T> IntoGrid<T> for (Vec<T>, Dimensions) {
+    fn into_grid(self) -> Result<Grid<T>, GridError> {
+        let (items, Dimensions { rows, cols }) = self;
+        let expected = row_col_length_check(rows, cols)?;
+        if expected == 0 {
+            return Err(GridError::InvalidSize);
+        }
+        if items.len() != expected {
+            return Err(GridError::SizeMismatch {
+                expected,
+                actual: items.len(),
+            });
+        }
+        Ok(Grid::create(items, rows, cols, None))
+    }
+}
+
+impl Grid<char> {
+    /// Parses text, such as a map where `#` is a wall and `.` is a floor, into a grid of `char`s.  Each line is a row, and each `char` in the line is a
+    /// cell.  Trailing empty lines are ignored.  Returns `RowSizeMismatch` if a line is not the same length as the first line, and `InvalidSize` if
+    /// there are no lines.
+    /// ```
+    /// use neighborgrid::*;
+    /// let map = "#.#\n...\n";
+    /// let grid = Grid::from_char_lines(map, None).expect("Failed to create Grid");
+    /// assert_eq!(grid.rows(), 2);
+    /// assert_eq!(grid.columns(), 3);
+    /// assert_eq!(grid.get((0, 0)), Some(&'#'));
+    /// assert_eq!(grid.get((1, 1)), Some(&'.'));
+    /// ```
+    pub fn from_char_lines(s: &str, options: Option<GridOptions>) -> Result<Grid<char>, GridError> {
+        let mut lines: Vec<Vec<char>> = s.lines().map(|line| line.chars().collect()).collect();
+        while lines.last().is_some_and(|line| line.is_empty()) {
+            lines.pop();
+        }
+        Grid::new(lines, options)
+    }
+}
+
 /// Impl for a tuple of (columns, rows, default_value)
-/// The default value is put into all cells  
+/// The default value is put into all cells.  Returns `InvalidSize` if either the columns or rows are zero.
 /// ```
 /// use neighborgrid::*;
 /// let grid = (3, 5, 2u8).into_grid().expect("Failed to create Grid");
@@ -62,6 +175,9 @@ impl<T: Clone> IntoGrid<T> for (Vec<T>, usize) {
 impl<T: Clone> IntoGrid<T> for (usize, usize, T) {
     fn into_grid(self) -> Result<Grid<T>, GridError> {
         let total = row_col_length_check(self.0, self.1)?;
+        if total == 0 {
+            return Err(GridError::InvalidSize);
+        }
         let items = vec![self.2; total];
         Ok(Grid {
             items,
@@ -73,7 +189,7 @@ impl<T: Clone> IntoGrid<T> for (usize, usize, T) {
 }
 
 /// isize::MAX is the max size for a vec.  Checks that excessive amount will not be allocated and panic.
-fn row_col_length_check(rows: usize, cols: usize) -> Result<usize, GridError> {
+pub(crate) fn row_col_length_check(rows: usize, cols: usize) -> Result<usize, GridError> {
     if rows >= i32::MAX as usize || cols >= i32::MAX as usize {
         return Err(GridError::ExcessiveSize);
     }
@@ -125,7 +241,18 @@ mod grid_tests {
             let mut vec = simple2d();
             vec[2].push(10);
             let grid = vec.into_grid();
-            assert!(matches!(grid, Err(GridError::RowSizeMismatch)));
+            assert!(matches!(
+                grid,
+                Err(GridError::RowSizeMismatch {
+                    row: 2,
+                    expected: 3,
+                    actual: 4
+                })
+            ));
+            assert_eq!(
+                grid.unwrap_err().to_string(),
+                "row 2 has length 4, expected 3"
+            );
         }
 
         #[test]
@@ -143,6 +270,154 @@ mod grid_tests {
         }
     }
 
+    mod array {
+        use super::*;
+
+        #[test]
+        fn should_create_grid_from_array() -> Result<()> {
+            let grid = [[1, 2, 3], [4, 5, 6]].into_grid()?;
+            assert_eq!(grid.rows(), 2);
+            assert_eq!(grid.columns(), 3);
+            assert_eq!(grid.items, vec![1, 2, 3, 4, 5, 6]);
+            Ok(())
+        }
+
+        #[test]
+        fn should_error_on_empty_array() {
+            let grid: Result<Grid<i32>> = [[], []].into_grid();
+            assert!(matches!(grid, Err(GridError::InvalidSize)));
+            let grid: Result<Grid<i32>> = [[0; 3]; 0].into_grid();
+            assert!(matches!(grid, Err(GridError::InvalidSize)));
+        }
+    }
+
+    mod flat_vec {
+        use super::*;
+
+        #[test]
+        fn should_create_grid_with_inferred_rows() -> Result<()> {
+            let grid = (vec![1, 2, 3, 4, 5, 6, 7, 8], Columns(4)).into_grid()?;
+            assert_eq!(grid.rows(), 2);
+            assert_eq!(grid.columns(), 4);
+            assert_eq!(grid.items, vec![1, 2, 3, 4, 5, 6, 7, 8]);
+
+            let grid = (vec![1, 2, 3], Columns(1)).into_grid()?;
+            assert_eq!(grid.rows(), 3);
+            assert_eq!(grid.columns(), 1);
+            Ok(())
+        }
+
+        #[test]
+        fn should_error_on_remainder() {
+            let grid = (vec![1, 2, 3, 4, 5, 6, 7], Columns(4)).into_grid();
+            assert!(matches!(grid, Err(GridError::InvalidSize)));
+        }
+
+        #[test]
+        fn should_error_on_no_columns() {
+            let grid = (vec![1, 2, 3], Columns(0)).into_grid();
+            assert!(matches!(grid, Err(GridError::InvalidSize)));
+            let grid: Result<Grid<i32>> = (vec![], Columns(3)).into_grid();
+            assert!(matches!(grid, Err(GridError::InvalidSize)));
+        }
+    }
+
+    mod dimensions {
+        use super::*;
+
+        #[test]
+        fn should_create_grid_with_dimensions() -> Result<()> {
+            let grid = (vec![1, 2, 3, 4, 5, 6], Dimensions { rows: 2, cols: 3 }).into_grid()?;
+            assert_eq!(grid.rows(), 2);
+            assert_eq!(grid.columns(), 3);
+            assert_eq!(grid.items, vec![1, 2, 3, 4, 5, 6]);
+            Ok(())
+        }
+
+        #[test]
+        fn should_error_on_wrong_length() {
+            let grid = (vec![1, 2, 3, 4, 5], Dimensions { rows: 2, cols: 3 }).into_grid();
+            assert!(matches!(
+                grid,
+                Err(GridError::SizeMismatch {
+                    expected: 6,
+                    actual: 5
+                })
+            ));
+            let grid: Result<Grid<i32>> = (vec![], Dimensions { rows: 0, cols: 3 }).into_grid();
+            assert!(matches!(grid, Err(GridError::InvalidSize)));
+        }
+
+        #[test]
+        fn should_error_on_overflowing_dimensions() {
+            let dimensions = Dimensions {
+                rows: usize::MAX,
+                cols: 2,
+            };
+            assert_eq!(dimensions.total(), None);
+            let grid = (vec![1, 2], dimensions).into_grid();
+            assert!(matches!(grid, Err(GridError::ExcessiveSize)));
+        }
+    }
+
+    mod char_lines {
+        use super::*;
+        use crate::grid::Origin;
+
+        #[test]
+        fn should_parse_map() -> Result<()> {
+            let map = "#####\n#..@#\n#.#.#\n#####\n\n\n";
+            let grid = Grid::from_char_lines(map, None)?;
+            assert_eq!(grid.rows(), 4);
+            assert_eq!(grid.columns(), 5);
+            assert_eq!(grid.get((0, 0)), Some(&'#'));
+            assert_eq!(grid.get((3, 1)), Some(&'@'));
+            assert_eq!(grid.get((2, 2)), Some(&'#'));
+            assert_eq!(grid.get((3, 2)), Some(&'.'));
+            assert_eq!(grid.get((0, 4)), None);
+
+            let gridoptions = GridOptions {
+                origin: Origin::LowerLeft,
+                inverted_y: false,
+                ..GridOptions::default()
+            };
+            let grid = Grid::from_char_lines("ab\r\ncd", Some(gridoptions))?;
+            assert_eq!(grid.get((0, 0)), Some(&'c'));
+            assert_eq!(grid.get((1, 1)), Some(&'b'));
+            Ok(())
+        }
+
+        #[test]
+        fn should_error_on_uneven_lines() {
+            let grid = Grid::from_char_lines("###\n#.\n###", None);
+            assert!(matches!(
+                grid,
+                Err(GridError::RowSizeMismatch {
+                    row: 1,
+                    expected: 3,
+                    actual: 2
+                })
+            ));
+            let grid = Grid::from_char_lines("###\n\n###", None);
+            assert!(matches!(
+                grid,
+                Err(GridError::RowSizeMismatch {
+                    row: 1,
+                    expected: 3,
+                    actual: 0
+                })
+            ));
+        }
+
+        #[test]
+        fn should_error_on_empty_text() {
+            let grid = Grid::from_char_lines("", None);
+            assert!(matches!(grid, Err(GridError::InvalidSize)));
+            let grid = Grid::from_char_lines("\n\n", None);
+            assert!(matches!(grid, Err(GridError::InvalidSize)));
+        }
+    }
+
     mod one_d_vec {
         use super::*;
 
@@ -180,4 +455,27 @@ mod grid_tests {
             assert!(matches!(grid, Err(GridError::InvalidSize)));
         }
     }
+
+    mod filled {
+        use super::*;
+
+        #[test]
+        fn should_create_filled_grid() -> Result<()> {
+            let grid = (3, 2, 'x').into_grid()?;
+            assert_eq!(grid.columns(), 3);
+            assert_eq!(grid.rows(), 2);
+            assert!(grid.iter().all(|c| *c == 'x'));
+            Ok(())
+        }
+
+        #[test]
+        fn should_error_on_zero_size() {
+            for (cols, rows) in [(3, 0), (0, 3), (0, 0)] {
+                let grid = Grid::new((cols, rows, 'x'), None);
+                assert!(matches!(grid, Err(GridError::InvalidSize)));
+            }
+            let grid: Result<Grid<i32>> = (Vec::new(), Dimensions { rows: 0, cols: 3 }).into_grid();
+            assert!(matches!(grid, Err(GridError::InvalidSize)));
+        }
+    }
 }
@@ -1,14 +1,21 @@
+mod builder;
 mod col_iters;
 mod error;
 mod grid;
 mod index;
 mod intogrid;
+mod line_iter;
 mod origin;
+#[cfg(feature = "rayon")]
+mod par_iter;
+mod pathfinding;
 mod quaditers;
+mod ring_iter;
 mod row_iters;
 mod xyneightbor;
+pub use builder::GridBuilder;
 pub use error::GridError;
 pub use grid::{Grid, GridOptions, Origin};
-pub use index::{Coordinates, Index};
-pub use intogrid::IntoGrid;
-pub use xyneightbor::{AllAroundNeighbor, XyNeighbor};
+pub use index::{Coordinates, GridIndex, Index};
+pub use intogrid::{Columns, Dimensions, IntoGrid};
+pub use xyneightbor::{AllAroundNeighbor, Direction, LabeledNeighbor, XyNeighbor};
@@ -0,0 +1,123 @@
+use crate::grid::Grid;
+
+/// Iterates over the cells along a straight line between two cells, using Bresenham's line algorithm
+pub struct LineIter<'a, T> {
+    grid: &'a Grid<T>,
+    x: isize,
+    y: isize,
+    end_x: isize,
+    end_y: isize,
+    dx: isize,
+    dy: isize,
+    step_x: isize,
+    step_y: isize,
+    err: isize,
+    done: bool,
+}
+
+impl<'a, T> LineIter<'a, T> {
+    /// `from` and `to` are internal vec indexes that have already been validated
+    pub(crate) fn new(grid: &'a Grid<T>, from: usize, to: usize) -> Self {
+        let (x, y) = ((from % grid.cols) as isize, (from / grid.cols) as isize);
+        let (end_x, end_y) = ((to % grid.cols) as isize, (to / grid.cols) as isize);
+        let dx = (end_x - x).abs();
+        let dy = -(end_y - y).abs();
+        LineIter {
+            grid,
+            x,
+            y,
+            end_x,
+            end_y,
+            dx,
+            dy,
+            step_x: if x < end_x { 1 } else { -1 },
+            step_y: if y < end_y { 1 } else { -1 },
+            err: dx + dy,
+            done: false,
+        }
+    }
+}
+
+impl<'a, T> Iterator for LineIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let item = &self.grid.items[self.y as usize * self.grid.cols + self.x as usize];
+        if self.x == self.end_x && self.y == self.end_y {
+            self.done = true;
+        } else {
+            let e2 = 2 * self.err;
+            if e2 >= self.dy {
+                self.err += self.dy;
+                self.x += self.step_x;
+            }
+            if e2 <= self.dx {
+                self.err += self.dx;
+                self.y += self.step_y;
+            }
+        }
+        Some(item)
+    }
+}
+
+#[cfg(test)]
+mod line_iter_tests {
+    use crate::error::GridError;
+    use crate::grid::Grid;
+
+    fn grid() -> Grid<i32> {
+        Grid::new_from_1d((0..25).collect(), 5, 5, None).unwrap()
+    }
+
+    #[test]
+    fn should_iter_horizontal_line() {
+        let grid = grid();
+        let line: Vec<_> = grid.line_iter((0, 1), (4, 1)).unwrap().collect();
+        let row: Vec<_> = grid.row_iter((0, 1)).collect();
+        assert_eq!(line, row);
+
+        let line: Vec<_> = grid.line_iter((3, 2), (1, 2)).unwrap().collect();
+        assert_eq!(line, vec![&13, &12, &11]);
+    }
+
+    #[test]
+    fn should_iter_vertical_line() {
+        let grid = grid();
+        let line: Vec<_> = grid.line_iter((2, 0), (2, 4)).unwrap().collect();
+        let col: Vec<_> = grid.col_iter((2, 0)).collect();
+        assert_eq!(line, col);
+    }
+
+    #[test]
+    fn should_iter_diagonal_line() {
+        let grid = grid();
+        let line: Vec<_> = grid.line_iter((0, 0), (4, 4)).unwrap().collect();
+        assert_eq!(line, vec![&0, &6, &12, &18, &24]);
+
+        let line: Vec<_> = grid.line_iter((0, 0), (4, 2)).unwrap().collect();
+        assert_eq!(line, vec![&0, &6, &7, &13, &14]);
+    }
+
+    #[test]
+    fn should_iter_single_cell() {
+        let grid = grid();
+        let line: Vec<_> = grid.line_iter((3, 3), (3, 3)).unwrap().collect();
+        assert_eq!(line, vec![&18]);
+    }
+
+    #[test]
+    fn should_err_on_invalid_endpoint() {
+        let grid = grid();
+        assert!(matches!(
+            grid.line_iter((0, 0), (-1, 0)),
+            Err(GridError::IndexOutOfBounds { .. })
+        ));
+        assert!(matches!(
+            grid.line_iter((0, -1), (0, 0)),
+            Err(GridError::IndexOutOfBounds { .. })
+        ));
+    }
+}
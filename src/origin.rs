@@ -1,4 +1,5 @@
-use crate::Grid;
+use crate::{Grid, GridError};
+use std::str::FromStr;
 
 /// Determines where (0,0) is on the grid.  Care should be taken with `Center`, especially with even number of rows or columns, for example:
 /// ```
@@ -28,7 +29,7 @@ use crate::Grid;
 /// ```
 ///
 /// In the above example, for `Origin::UpperLeft`, `(0,0)` would be the cell with a `1`, or a `13` for `Origin::LowerLeft`  
-#[derive(Debug, Clone, PartialEq, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
 pub enum Origin {
     #[default]
     UpperLeft,
@@ -36,13 +37,44 @@ pub enum Origin {
     LowerLeft,
 }
 
+impl Origin {
+    /// Every `Origin`
+    pub const ALL: [Origin; 3] = [Origin::UpperLeft, Origin::Center, Origin::LowerLeft];
+
+    /// The name of the variant, such as `"Center"`.  Can be parsed back into an `Origin` with `str::parse`.
+    /// ```
+    /// use neighborgrid::*;
+    /// assert_eq!(Origin::LowerLeft.as_str(), "LowerLeft");
+    /// assert_eq!("lowerleft".parse::<Origin>().expect("unknown origin"), Origin::LowerLeft);
+    /// ```
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Origin::UpperLeft => "UpperLeft",
+            Origin::Center => "Center",
+            Origin::LowerLeft => "LowerLeft",
+        }
+    }
+}
+
+/// Parses the variant names, ignoring case.  Returns `UnknownOrigin` for anything else.
+impl FromStr for Origin {
+    type Err = GridError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Origin::ALL
+            .into_iter()
+            .find(|origin| origin.as_str().eq_ignore_ascii_case(s))
+            .ok_or_else(|| GridError::UnknownOrigin(s.to_string()))
+    }
+}
+
 /// Assumptions is that the grid cannot be larger than isize::MAX, which is a fair assumption since the largest Vec in stdlib is isize::MAX
 impl Origin {
     #[inline]
     pub(crate) fn max_x<T>(&self, grid: &Grid<T>) -> isize {
         match self {
             Origin::Center => (grid.cols as isize - 1) / 2,
-            Origin::LowerLeft | Origin::UpperLeft => grid.cols as isize,
+            Origin::LowerLeft | Origin::UpperLeft => grid.cols as isize - 1,
         }
     }
 
@@ -57,8 +89,8 @@ impl Origin {
     #[inline]
     pub(crate) fn max_y<T>(&self, grid: &Grid<T>) -> isize {
         match self {
-            Origin::Center => (grid.rows + 1) as isize / 2,
-            Origin::LowerLeft => grid.rows as isize,
+            Origin::Center => grid.rows as isize / 2,
+            Origin::LowerLeft => grid.rows as isize - 1,
             Origin::UpperLeft => 0,
         }
     }
@@ -66,9 +98,44 @@ impl Origin {
     #[inline]
     pub(crate) fn min_y<T>(&self, grid: &Grid<T>) -> isize {
         match self {
-            Origin::Center => -(grid.rows as isize / 2),
+            Origin::Center => -((grid.rows as isize - 1) / 2),
             Origin::LowerLeft => 0,
-            Origin::UpperLeft => -(grid.rows as isize),
+            Origin::UpperLeft => -(grid.rows as isize - 1),
+        }
+    }
+}
+
+#[cfg(test)]
+mod origin_tests {
+    use super::*;
+
+    #[test]
+    fn should_round_trip_names() {
+        for origin in Origin::ALL {
+            assert_eq!(Origin::from_str(origin.as_str()).unwrap(), origin);
+        }
+    }
+
+    #[test]
+    fn should_parse_ignoring_case() {
+        assert_eq!("center".parse::<Origin>().unwrap(), Origin::Center);
+        assert_eq!("UPPERLEFT".parse::<Origin>().unwrap(), Origin::UpperLeft);
+        assert_eq!("lowerLeft".parse::<Origin>().unwrap(), Origin::LowerLeft);
+    }
+
+    #[test]
+    fn should_err_on_unknown_origin() {
+        for s in ["", "upper left", "Centre", "UpperLeft "] {
+            let res = s.parse::<Origin>();
+            assert!(
+                matches!(&res, Err(GridError::UnknownOrigin(text)) if text == s),
+                "{:?}",
+                res
+            );
         }
+        assert_eq!(
+            "middle".parse::<Origin>().unwrap_err().to_string(),
+            "Unknown origin \"middle\""
+        );
     }
 }
@@ -0,0 +1,68 @@
+use crate::grid::Grid;
+use crate::index::Index;
+use rayon::prelude::*;
+
+impl<T: Sync> Grid<T> {
+    /// Parallel iterator over all elements.  Requires the `rayon` feature.
+    pub fn par_iter(&self) -> impl IndexedParallelIterator<Item = &T> {
+        self.items.par_iter()
+    }
+
+    /// Parallel iterator over all elements, along with the coordinate of each cell.  The coordinate is based on the `Origin` and other `GridOptions`.
+    /// Requires the `rayon` feature.
+    pub fn par_iter_coords(&self) -> impl IndexedParallelIterator<Item = ((isize, isize), &T)> {
+        self.items
+            .par_iter()
+            .enumerate()
+            .map(move |(i, item)| (Index::output(i, self), item))
+    }
+}
+
+impl<T: Send> Grid<T> {
+    /// Mutable parallel iterator over all elements.  Requires the `rayon` feature.
+    pub fn par_iter_mut(&mut self) -> impl IndexedParallelIterator<Item = &mut T> {
+        self.items.par_iter_mut()
+    }
+}
+
+#[cfg(test)]
+mod par_iter_tests {
+    use super::*;
+    use crate::grid::{GridOptions, Origin};
+
+    fn grid() -> Grid<i64> {
+        let gridoptions = GridOptions {
+            origin: Origin::Center,
+            inverted_y: false,
+            ..GridOptions::default()
+        };
+        Grid::new_from_1d((0..10_000).collect(), 100, 100, Some(gridoptions)).unwrap()
+    }
+
+    #[test]
+    fn should_sum_in_parallel() {
+        let grid = grid();
+        let serial: i64 = grid.iter().sum();
+        let parallel: i64 = grid.par_iter().sum();
+        assert_eq!(serial, parallel);
+    }
+
+    #[test]
+    fn should_mutate_in_parallel() {
+        let mut grid = grid();
+        grid.par_iter_mut().for_each(|v| *v *= 2);
+        assert_eq!(grid.get(1), Some(&2));
+        assert_eq!(grid.par_iter().sum::<i64>(), grid.iter().sum::<i64>());
+        assert_eq!(grid.iter().sum::<i64>(), (0..10_000).sum::<i64>() * 2);
+    }
+
+    #[test]
+    fn should_supply_coordinates() {
+        let grid = grid();
+        let coords: Vec<_> = grid.par_iter_coords().collect();
+        assert_eq!(coords.len(), grid.size());
+        for (coord, value) in coords {
+            assert_eq!(grid.get(coord), Some(value));
+        }
+    }
+}
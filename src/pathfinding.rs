@@ -0,0 +1,170 @@
+use crate::error::GridError;
+use crate::grid::Grid;
+use crate::index::{GridIndex, Index};
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+impl<T> Grid<T> {
+    /// Finds the shortest path between `start` and `goal` using A*, where every move between cells has the same cost.  Cells where `passable` returns false
+    /// are treated as walls.  When `diagonal` is true, the diagonal neighbors are also considered to be one move away.  `GridOptions` wrapping is honored.
+    ///
+    /// Returns `Ok(None)` if there is no path, otherwise the coordinates of each cell along the path, including both `start` and `goal`.
+    /// ```
+    /// use neighborgrid::*;
+    /// let vec = vec![
+    ///             vec!['.', '#', '.'],
+    ///             vec!['.', '#', '.'],
+    ///             vec!['.', '.', '.'],
+    /// ];
+    /// let grid = Grid::new(vec, None).expect("failed to import 2d vec");
+    /// let path = grid
+    ///     .astar((0, 0), (2, 0), |c| *c != '#', false)
+    ///     .expect("invalid coordinate")
+    ///     .expect("no path");
+    /// assert_eq!(path, vec![(0, 0), (0, 1), (0, 2), (1, 2), (2, 2), (2, 1), (2, 0)]);
+    /// ```
+    pub fn astar<I: GridIndex, F: Fn(&T) -> bool>(
+        &self,
+        start: I,
+        goal: I,
+        passable: F,
+        diagonal: bool,
+    ) -> Result<Option<Vec<(isize, isize)>>, GridError> {
+        let start = start.grid_index(self)?;
+        let goal = goal.grid_index(self)?;
+        if !passable(&self.items[goal]) {
+            return Ok(None);
+        }
+
+        let mut came_from: Vec<Option<usize>> = vec![None; self.size()];
+        let mut cost = vec![usize::MAX; self.size()];
+        let mut open = BinaryHeap::new();
+        cost[start] = 0;
+        open.push(Reverse((
+            self.path_heuristic(start, goal, diagonal),
+            0,
+            start,
+        )));
+
+        while let Some(Reverse((_, current_cost, current))) = open.pop() {
+            if current == goal {
+                let mut path = vec![Index::output(current, self)];
+                let mut current = current;
+                while let Some(previous) = came_from[current] {
+                    path.push(Index::output(previous, self));
+                    current = previous;
+                }
+                path.reverse();
+                return Ok(Some(path));
+            }
+            // A cheaper route to this cell has already been processed
+            if current_cost > cost[current] {
+                continue;
+            }
+            for next in self.neighbor_indexes(current, diagonal) {
+                let next_cost = current_cost + 1;
+                if next_cost < cost[next] && passable(&self.items[next]) {
+                    cost[next] = next_cost;
+                    came_from[next] = Some(current);
+                    let estimate = next_cost + self.path_heuristic(next, goal, diagonal);
+                    open.push(Reverse((estimate, next_cost, next)));
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    /// Manhattan distance, or Chebyshev distance when moving diagonally, taking the shorter way around when the grid wraps
+    fn path_heuristic(&self, a: usize, b: usize, diagonal: bool) -> usize {
+        let (dx, dy) = self.distance_deltas(a, b);
+        if diagonal {
+            dx.max(dy)
+        } else {
+            dx + dy
+        }
+    }
+}
+
+#[cfg(test)]
+mod pathfinding_tests {
+    use super::*;
+    use crate::grid::GridOptions;
+
+    fn maze() -> Grid<u8> {
+        let vec = vec![
+            vec![0, 0, 0, 0, 0],
+            vec![0, 1, 1, 1, 0],
+            vec![0, 0, 0, 1, 0],
+            vec![1, 1, 0, 1, 0],
+            vec![0, 0, 0, 0, 0],
+        ];
+        Grid::new(vec, None).unwrap()
+    }
+
+    #[test]
+    fn should_find_shortest_path_around_walls() {
+        let grid = maze();
+        let path = grid.astar((0, 0), (2, 2), |c| *c == 0, false).unwrap();
+        assert_eq!(path, Some(vec![(0, 0), (0, 1), (0, 2), (1, 2), (2, 2)]));
+
+        let path = grid
+            .astar((0, 4), (4, 2), |c| *c == 0, false)
+            .unwrap()
+            .unwrap();
+        assert_eq!(path.len(), 7);
+        assert_eq!(path.first(), Some(&(0, 4)));
+        assert_eq!(path.last(), Some(&(4, 2)));
+        for (x, y) in path {
+            assert_eq!(grid.get((x, y)), Some(&0));
+        }
+    }
+
+    #[test]
+    fn should_return_none_without_path() {
+        let vec = vec![vec![0, 1, 0], vec![0, 1, 0], vec![0, 1, 0]];
+        let grid = Grid::new(vec, None).unwrap();
+        assert_eq!(
+            grid.astar((0, 0), (2, 2), |c| *c == 0, false).unwrap(),
+            None
+        );
+        assert_eq!(grid.astar((0, 0), (1, 1), |c| *c == 0, true).unwrap(), None);
+
+        let grid = maze();
+        assert_eq!(
+            grid.astar((0, 0), (1, 1), |c| *c == 0, false).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn should_move_diagonally() {
+        let grid = maze();
+        let path = grid.astar((0, 0), (2, 2), |c| *c == 0, true).unwrap();
+        assert_eq!(path, Some(vec![(0, 0), (0, 1), (1, 2), (2, 2)]));
+    }
+
+    #[test]
+    fn should_path_to_self() {
+        let grid = maze();
+        let path = grid.astar((2, 2), (2, 2), |c| *c == 0, false).unwrap();
+        assert_eq!(path, Some(vec![(2, 2)]));
+    }
+
+    #[test]
+    fn should_path_across_wrapped_edge() {
+        let gridoptions = GridOptions {
+            wrap_x: true,
+            ..GridOptions::default()
+        };
+        let grid = Grid::new(vec![vec![0, 0, 0, 0, 0]], Some(gridoptions)).unwrap();
+        let path = grid.astar((0, 0), (4, 0), |c| *c == 0, false).unwrap();
+        assert_eq!(path, Some(vec![(0, 0), (4, 0)]));
+    }
+
+    #[test]
+    fn should_err_on_invalid_endpoint() {
+        let grid = maze();
+        let path = grid.astar((0, 0), (-1, 0), |c| *c == 0, false);
+        assert!(matches!(path, Err(GridError::IndexOutOfBounds { .. })));
+    }
+}
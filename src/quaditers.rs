@@ -3,9 +3,10 @@ use crate::Grid;
 pub struct NrantIterator<'a, T> {
     grid: &'a Grid<T>,
     current: usize,
+    // One past the last position yet to be returned from the back
+    end: usize,
     start: usize,
     rwidth: usize,
-    rheight: usize,
 }
 
 impl<'a, T> NrantIterator<'a, T> {
@@ -16,9 +17,9 @@ impl<'a, T> NrantIterator<'a, T> {
         Self {
             grid,
             current: 0,
+            end: rwidth * rheight,
             start,
             rwidth,
-            rheight,
         }
     }
 
@@ -27,33 +28,56 @@ impl<'a, T> NrantIterator<'a, T> {
         NrantIterator {
             grid,
             current: 100,
+            end: 0,
             start: 0,
             rwidth: 1,
-            rheight: 1,
         }
     }
+
+    /// The cell at the position within the nrant. `None` if the position overruns the grid
+    fn cell(&self, position: usize) -> Option<&'a T> {
+        let row_offset = position / self.rwidth;
+        let col_offset = position % self.rwidth;
+        // Check for overrunning the grid
+        if col_offset + (self.start % self.grid.columns()) >= self.grid.columns() {
+            return None;
+        }
+        let index = self.start + row_offset * self.grid.columns() + col_offset;
+        self.grid.get(index)
+    }
 }
 
 impl<'a, T> Iterator for NrantIterator<'a, T> {
     type Item = Option<&'a T>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.current >= self.rwidth * self.rheight {
+        if self.current >= self.end {
             return None;
         }
-        let row_offset = self.current / self.rwidth;
-        let col_offset = self.current % self.rwidth;
-        // Check for overrunning the grid
-        if col_offset + (self.start % self.grid.columns()) >= self.grid.columns() {
-            self.current += 1;
-            return Some(None);
-        }
-        let index = self.start + row_offset * self.grid.columns() + col_offset;
+        let cell = self.cell(self.current);
         self.current += 1;
-        return Some(self.grid.get(index));
+        Some(cell)
+    }
+
+    /// Cells that overrun the edge of the grid are still counted, as they are returned as `Some(None)`
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.end.saturating_sub(self.current);
+        (remaining, Some(remaining))
     }
 }
 
+impl<'a, T> DoubleEndedIterator for NrantIterator<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.current >= self.end {
+            return None;
+        }
+        self.end -= 1;
+        Some(self.cell(self.end))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for NrantIterator<'a, T> {}
+
 #[cfg(test)]
 mod nrant_iterator_tests {
     use super::*;
@@ -115,6 +139,73 @@ mod nrant_iterator_tests {
         assert_eq!(iter.next(), None);
     }
 
+    #[test]
+    fn should_report_exact_len() {
+        let vec = vec![vec![0, 1, 2], vec![3, 4, 5]];
+        let grid = Grid::new(vec, None).unwrap();
+
+        // Overrun cells are included in the length
+        let mut iter = NrantIterator::new(&grid, 2, 2);
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.next(), Some(Some(&2)));
+        assert_eq!(iter.len(), 1);
+        assert_eq!(iter.next(), Some(None));
+        assert_eq!(iter.len(), 0);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.len(), 0);
+
+        let mut vec = vec![];
+        for i in 1..=81 {
+            vec.push(i);
+        }
+        let grid = Grid::new_from_1d(vec, 9, 9, None).unwrap();
+        let mut iter = grid.nrant_iter(3, 40);
+        assert_eq!(iter.len(), 9);
+        for remaining in (0..9).rev() {
+            assert!(iter.next().is_some());
+            assert_eq!(iter.len(), remaining);
+        }
+        assert_eq!(grid.nrant_iter(3, 40).count(), 9);
+        assert_eq!(grid.quadrant_iter((10, 10)).len(), 0);
+    }
+
+    #[test]
+    fn should_iterate_in_reverse() {
+        let grid = Grid::new_from_1d((1..=81).collect(), 9, 9, None).unwrap();
+
+        let forward: Vec<_> = NrantIterator::new(&grid, 3, 40).collect();
+        let mut backward: Vec<_> = NrantIterator::new(&grid, 3, 40).rev().collect();
+        assert_eq!(backward.len(), 9);
+        backward.reverse();
+        assert_eq!(forward, backward);
+
+        let mut iter = NrantIterator::new(&grid, 3, 40);
+        assert_eq!(iter.next_back(), Some(Some(&51)));
+        assert_eq!(iter.next(), Some(Some(&31)));
+        assert_eq!(iter.next_back(), Some(Some(&50)));
+        assert_eq!(iter.len(), 6);
+        assert_eq!(iter.next(), Some(Some(&32)));
+        assert_eq!(iter.next(), Some(Some(&33)));
+        assert_eq!(iter.next(), Some(Some(&40)));
+        assert_eq!(iter.next_back(), Some(Some(&49)));
+        assert_eq!(iter.next_back(), Some(Some(&42)));
+        assert_eq!(iter.next(), Some(Some(&41)));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn should_iterate_overrun_in_reverse() {
+        let vec = vec![vec![0, 1, 2], vec![3, 4, 5]];
+        let grid = Grid::new(vec, None).unwrap();
+
+        let mut iter = NrantIterator::new(&grid, 2, 2).rev();
+        assert_eq!(iter.next(), Some(None));
+        assert_eq!(iter.next(), Some(Some(&2)));
+        assert_eq!(iter.next(), None);
+        assert_eq!(grid.quadrant_iter((10, 10)).next_back(), None);
+    }
+
     #[test]
     fn test_noop() {
         let mut vec = vec![];
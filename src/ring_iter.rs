@@ -0,0 +1,227 @@
+use crate::error::GridError;
+use crate::grid::Grid;
+
+/// Iterates clockwise over the cells that are exactly `radius` cells away from a center cell, starting from the upper left corner of the ring.
+/// Returns `Some(None)` for positions that are off the edge of a non-wrapping grid.
+pub struct RingIter<'a, T> {
+    grid: &'a Grid<T>,
+    col: isize,
+    row: isize,
+    radius: isize,
+    // Internal row direction that is considered "up", following the same rules as `get_up`
+    up: isize,
+    len: usize,
+    current: usize,
+}
+
+impl<'a, T> RingIter<'a, T> {
+    /// `index` is an internal vec index that has already been validated.  On a grid that does not wrap, a ring that is entirely off the grid is empty.
+    /// Otherwise the ring has `8 * radius` positions, which must fit in an `isize` so that the offsets cannot overflow.
+    pub(crate) fn new(grid: &'a Grid<T>, index: usize, radius: usize) -> Result<Self, GridError> {
+        let up = if grid.options.inverted_y && grid.options.neighbor_ybased {
+            1
+        } else {
+            -1
+        };
+        let (col, row) = (index % grid.cols, index / grid.cols);
+        // The furthest any cell of the grid is from the center, so every larger ring is off the grid
+        let furthest = col
+            .max(grid.cols - 1 - col)
+            .max(row)
+            .max(grid.rows - 1 - row);
+        let wraps = grid.options.wrap_x || grid.options.wrap_y;
+        let (radius, len) = match radius {
+            0 => (0, 1),
+            r if r > furthest && !wraps => (0, 0),
+            r => match r.checked_mul(8) {
+                Some(len) if len <= isize::MAX as usize => (r, len),
+                _ => return Err(GridError::ExcessiveSize),
+            },
+        };
+        Ok(RingIter {
+            grid,
+            col: col as isize,
+            row: row as isize,
+            radius: radius as isize,
+            up,
+            len,
+            current: 0,
+        })
+    }
+
+    fn len_total(&self) -> usize {
+        self.len
+    }
+
+    /// The (x, up) offset from the center for the nth cell of the ring
+    fn offset(&self, n: isize) -> (isize, isize) {
+        let r = self.radius;
+        let side = 2 * r;
+        match n / side.max(1) {
+            0 => (-r + n, r),
+            1 => (r, r - (n - side)),
+            2 => (r - (n - 2 * side), -r),
+            _ => (-r, -r + (n - 3 * side)),
+        }
+    }
+}
+
+impl<'a, T> Iterator for RingIter<'a, T> {
+    type Item = Option<&'a T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.current >= self.len_total() {
+            return None;
+        }
+        let (dx, dup) = self.offset(self.current as isize);
+        self.current += 1;
+
+        let grid = self.grid;
+        let wrap = |value: isize, size: usize, wrapping: bool| {
+            if (0..size as isize).contains(&value) {
+                Some(value as usize)
+            } else if wrapping {
+                Some(value.rem_euclid(size as isize) as usize)
+            } else {
+                None
+            }
+        };
+        let col = wrap(self.col + dx, grid.cols, grid.options.wrap_x);
+        let row = wrap(self.row + dup * self.up, grid.rows, grid.options.wrap_y);
+        match (col, row) {
+            (Some(col), Some(row)) => Some(Some(&grid.items[row * grid.cols + col])),
+            _ => Some(None),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.len_total() - self.current;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for RingIter<'a, T> {}
+
+#[cfg(test)]
+mod ring_iter_tests {
+    use crate::error::GridError;
+    use crate::grid::{Grid, GridOptions};
+
+    fn grid(wrap: bool) -> Grid<i32> {
+        let gridoptions = GridOptions {
+            wrap_x: wrap,
+            wrap_y: wrap,
+            ..GridOptions::default()
+        };
+        Grid::new_from_1d((0..25).collect(), 5, 5, Some(gridoptions)).unwrap()
+    }
+
+    #[test]
+    fn should_match_all_around_neighbors() {
+        for wrap in [false, true] {
+            let grid = grid(wrap);
+            for index in [0usize, 7, 12, 24] {
+                let n = grid.all_around_neighbors(index).unwrap();
+                let expected = vec![
+                    n.upleft,
+                    n.up,
+                    n.upright,
+                    n.right,
+                    n.downright,
+                    n.down,
+                    n.downleft,
+                    n.left,
+                ];
+                let ring: Vec<_> = grid.ring_iter(index, 1).unwrap().collect();
+                assert_eq!(ring, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn should_iter_ring_radius_two() {
+        let gridoptions = GridOptions {
+            neighbor_ybased: false,
+            ..GridOptions::default()
+        };
+        let grid = Grid::new_from_1d((0..25).collect(), 5, 5, Some(gridoptions)).unwrap();
+        let ring: Vec<_> = grid.ring_iter((2, 2), 2).unwrap().flatten().collect();
+        assert_eq!(
+            ring,
+            vec![&0, &1, &2, &3, &4, &9, &14, &19, &24, &23, &22, &21, &20, &15, &10, &5]
+        );
+
+        let mut iter = grid.ring_iter((0, 0), 2).unwrap();
+        assert_eq!(iter.len(), 16);
+        let ring: Vec<_> = iter.by_ref().take(5).collect();
+        assert_eq!(ring, vec![None; 5]);
+        assert_eq!(iter.len(), 11);
+        let ring: Vec<_> = iter.flatten().collect();
+        assert_eq!(ring, vec![&2, &7, &12, &11, &10]);
+    }
+
+    #[test]
+    fn should_wrap_ring() {
+        let grid = grid(true);
+        let ring: Vec<_> = grid.ring_iter((0, 0), 2).unwrap().flatten().collect();
+        assert_eq!(ring.len(), 16);
+        assert_eq!(ring[0], &13);
+    }
+
+    #[test]
+    fn should_iter_center_for_radius_zero() {
+        let grid = grid(false);
+        let ring: Vec<_> = grid.ring_iter((1, 1), 0).unwrap().collect();
+        assert_eq!(ring, vec![Some(&6)]);
+    }
+
+    #[test]
+    fn should_be_empty_for_ring_off_the_grid() {
+        let grid = grid(false);
+        let mut ring: Vec<_> = grid.ring_iter((4, 4), 4).unwrap().flatten().collect();
+        ring.sort();
+        assert_eq!(ring, vec![&0, &1, &2, &3, &4, &5, &10, &15, &20]);
+        assert_eq!(grid.ring_iter((2, 2), 2).unwrap().len(), 16);
+        for (center, radius) in [((4, 4), 5), ((2, 2), 3), ((0, 0), 6), ((0, 0), usize::MAX)] {
+            let mut iter = grid.ring_iter(center, radius).unwrap();
+            assert_eq!(iter.len(), 0);
+            assert_eq!(iter.next(), None);
+        }
+    }
+
+    #[test]
+    fn should_wrap_rings_larger_than_grid() {
+        let grid = grid(true);
+        let ring: Vec<_> = grid.ring_iter((0, 0), 6).unwrap().collect();
+        assert_eq!(ring.len(), 48);
+        assert!(ring.iter().all(Option::is_some));
+        // Each corner is 6 cells away on both axes, which wraps to 1 away
+        assert_eq!(ring[0], grid.ring_iter((0, 0), 1).unwrap().next().unwrap());
+
+        let gridoptions = GridOptions {
+            wrap_x: true,
+            ..GridOptions::default()
+        };
+        let grid = Grid::new_from_1d((0..25).collect(), 5, 5, Some(gridoptions)).unwrap();
+        assert_eq!(grid.ring_iter((0, 0), 6).unwrap().flatten().count(), 10);
+        assert!(matches!(
+            grid.ring_iter((0, 0), usize::MAX),
+            Err(GridError::ExcessiveSize)
+        ));
+        assert_eq!(
+            grid.ring_iter((0, 0), isize::MAX as usize / 8)
+                .unwrap()
+                .len(),
+            isize::MAX as usize / 8 * 8
+        );
+    }
+
+    #[test]
+    fn should_err_on_invalid_center() {
+        let grid = grid(false);
+        assert!(matches!(
+            grid.ring_iter((-1, 0), 1),
+            Err(GridError::IndexOutOfBounds { .. })
+        ));
+    }
+}
@@ -9,8 +9,20 @@ impl<'a, T> Iterator for RowIter<'a, T> {
     fn next(&mut self) -> Option<Self::Item> {
         self.slice.next()
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.slice.size_hint()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for RowIter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.slice.next_back()
+    }
 }
 
+impl<'a, T> ExactSizeIterator for RowIter<'a, T> {}
+
 impl<'a, T> RowIter<'a, T> {
     pub(crate) fn new(grid: &'a Grid<T>, index: usize) -> RowIter<'a, T> {
         let row_start = crate::grid::row_start_index(grid, index);
@@ -34,8 +46,20 @@ impl<'a, T> Iterator for MutRowIter<'a, T> {
     fn next(&mut self) -> Option<Self::Item> {
         self.slice.next()
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.slice.size_hint()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for MutRowIter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.slice.next_back()
+    }
 }
 
+impl<'a, T> ExactSizeIterator for MutRowIter<'a, T> {}
+
 impl<'a, T> MutRowIter<'a, T> {
     pub(crate) fn new(grid: &'a mut Grid<T>, index: usize) -> MutRowIter<'a, T> {
         let row_start = crate::grid::row_start_index(grid, index);
@@ -123,6 +147,42 @@ mod iter_tests {
             assert_eq!(iter.next(), Some(&mut 6));
             assert_eq!(iter.next(), None);
         }
+
+        #[test]
+        fn should_iter_over_row_in_reverse() {
+            let grid = center_grid();
+            let mut iter = grid.row_iter(4).rev();
+            assert_eq!(iter.next(), Some(&5));
+            assert_eq!(iter.next(), Some(&4));
+            assert_eq!(iter.next(), Some(&3));
+            assert_eq!(iter.next(), None);
+
+            let mut iter = RowIter::new(&grid, 12);
+            assert_eq!(iter.next_back(), Some(&14));
+            assert_eq!(iter.next(), Some(&12));
+            assert_eq!(iter.next_back(), Some(&13));
+            assert_eq!(iter.next(), None);
+            assert_eq!(iter.next_back(), None);
+        }
+
+        #[test]
+        fn should_mut_iter_over_row_in_reverse() {
+            let mut grid = center_grid();
+            let mut iter = MutRowIter::new(&mut grid, 3);
+            assert_eq!(iter.next_back(), Some(&mut 5));
+            assert_eq!(iter.next(), Some(&mut 3));
+            assert_eq!(iter.next_back(), Some(&mut 4));
+            assert_eq!(iter.next(), None);
+
+            for (i, value) in MutRowIter::new(&mut grid, 3).rev().enumerate() {
+                *value = i as i32;
+            }
+            let mut iter = RowIter::new(&grid, 3);
+            assert_eq!(iter.next(), Some(&2));
+            assert_eq!(iter.next(), Some(&1));
+            assert_eq!(iter.next(), Some(&0));
+            assert_eq!(iter.next(), None);
+        }
     }
 }
 // pub(crate) struct GridIter<'a, T, C>
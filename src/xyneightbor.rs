@@ -1,4 +1,51 @@
-/// Represents (0,1), (0,1), (-1,0), (1,0) relative values to the specific point
+/// The direction of a neighbor relative to a cell.  Which way "up" is follows the same rules as `Grid::get_up`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+    UpLeft,
+    UpRight,
+    DownLeft,
+    DownRight,
+}
+
+impl Direction {
+    /// The up, left, right and down directions, in the order that `XyNeighbor::iter` returns them
+    pub const CARDINAL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Left,
+        Direction::Right,
+        Direction::Down,
+    ];
+
+    /// Every direction, clockwise starting from up
+    pub const CLOCKWISE: [Direction; 8] = [
+        Direction::Up,
+        Direction::UpRight,
+        Direction::Right,
+        Direction::DownRight,
+        Direction::Down,
+        Direction::DownLeft,
+        Direction::Left,
+        Direction::UpLeft,
+    ];
+
+    /// The diagonal directions, top to bottom and left to right
+    pub const DIAGONAL: [Direction; 4] = [
+        Direction::UpLeft,
+        Direction::UpRight,
+        Direction::DownLeft,
+        Direction::DownRight,
+    ];
+}
+
+/// A neighbor of a cell, with the `Direction` it is in, its coordinate, and its value
+pub type LabeledNeighbor<'a, T> = (Direction, (isize, isize), &'a T);
+
+/// Represents the four cardinal neighbors of a specific point, with relative values of up `(0,1)`, left `(-1,0)`, right `(1,0)`, and down `(0,-1)`.
+/// Fields are declared in the same order that `iter` returns them.
 #[derive(Debug, Clone, PartialEq)]
 pub struct XyNeighbor<'a, T> {
     pub up: Option<&'a T>,
@@ -12,12 +59,25 @@ impl<'a, T> XyNeighbor<'a, T> {
     /// `Option` is if there is a neighbor in that position.  Using this around a cell on the edge of the grid will return some inner `None`s.
     ///
     /// Follows top to bottom, left to right.  So up (positive y value), left, right, down.
-    pub fn iter(&self) -> XyNeighIterator<Option<&T>> {
+    pub fn iter(&self) -> XyNeighIterator<'_, Option<&T>> {
         XyNeighIterator {
             refs: [&self.up, &self.left, &self.right, &self.down],
             current: 0,
         }
     }
+
+    /// Returns the neighbor in `direction`.  Always `None` for the diagonal directions, which are not included in an `XyNeighbor`.
+    pub fn get(&self, direction: Direction) -> Option<&'a T> {
+        match direction {
+            Direction::Up => self.up,
+            Direction::Left => self.left,
+            Direction::Right => self.right,
+            Direction::Down => self.down,
+            Direction::UpLeft | Direction::UpRight | Direction::DownLeft | Direction::DownRight => {
+                None
+            }
+        }
+    }
 }
 
 pub struct XyNeighIterator<'a, V> {
@@ -54,7 +114,7 @@ impl<'a, T> AllAroundNeighbor<'a, T> {
     /// `Option` is if there is a neighbor in that position.  Using this around a cell on the edge of the grid will return some inner `None`s.
     ///
     /// Follows top to bottom, left to right.  So upleft (positive y value), up, upright, left, right, downleft, down, downright.
-    pub fn iter(&self) -> AllAroundNeighIterator<Option<&T>> {
+    pub fn iter(&self) -> AllAroundNeighIterator<'_, Option<&T>> {
         AllAroundNeighIterator {
             refs: [
                 &self.upleft,
@@ -69,8 +129,29 @@ impl<'a, T> AllAroundNeighbor<'a, T> {
             current: 0,
         }
     }
-}
 
+    /// Returns an iterator over the neighbors clockwise, starting from up: up, upright, right, downright, down, downleft, left, upleft.  Like `iter`,
+    /// neighbors that are not in the grid are `None`.
+    pub fn iter_clockwise(&self) -> impl Iterator<Item = Option<&'a T>> + '_ {
+        Direction::CLOCKWISE
+            .iter()
+            .map(|direction| self.get(*direction))
+    }
+
+    /// Returns the neighbor in `direction`, such as the direction a character is facing
+    pub fn get(&self, direction: Direction) -> Option<&'a T> {
+        match direction {
+            Direction::Up => self.up,
+            Direction::Down => self.down,
+            Direction::Left => self.left,
+            Direction::Right => self.right,
+            Direction::UpLeft => self.upleft,
+            Direction::UpRight => self.upright,
+            Direction::DownLeft => self.downleft,
+            Direction::DownRight => self.downright,
+        }
+    }
+}
 
 pub struct AllAroundNeighIterator<'a, V> {
     refs: [&'a V; 8],
@@ -152,4 +233,77 @@ mod xyneightbor_tests {
         assert_eq!(iter.next(), Some(&Some(&6)));
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn xyneightbor_get_test() {
+        let neigh = XyNeighbor {
+            up: Some(&1),
+            left: None,
+            right: Some(&3),
+            down: Some(&4),
+        };
+
+        assert_eq!(neigh.get(Direction::Up), Some(&1));
+        assert_eq!(neigh.get(Direction::Left), None);
+        assert_eq!(neigh.get(Direction::Right), Some(&3));
+        assert_eq!(neigh.get(Direction::Down), Some(&4));
+        for direction in Direction::DIAGONAL {
+            assert_eq!(neigh.get(direction), None);
+        }
+        let by_direction: Vec<_> = Direction::CARDINAL.iter().map(|d| neigh.get(*d)).collect();
+        let iterated: Vec<_> = neigh.iter().copied().collect();
+        assert_eq!(by_direction, iterated);
+    }
+
+    #[test]
+    fn all_around_neightbor_get_test() {
+        let neigh = AllAroundNeighbor {
+            upleft: Some(&1),
+            up: Some(&2),
+            upright: None,
+            left: Some(&3),
+            right: Some(&4),
+            downleft: None,
+            down: Some(&5),
+            downright: Some(&6),
+        };
+
+        assert_eq!(neigh.get(Direction::UpLeft), Some(&1));
+        assert_eq!(neigh.get(Direction::Up), Some(&2));
+        assert_eq!(neigh.get(Direction::UpRight), None);
+        assert_eq!(neigh.get(Direction::Left), Some(&3));
+        assert_eq!(neigh.get(Direction::Right), Some(&4));
+        assert_eq!(neigh.get(Direction::DownLeft), None);
+        assert_eq!(neigh.get(Direction::Down), Some(&5));
+        assert_eq!(neigh.get(Direction::DownRight), Some(&6));
+    }
+
+    #[test]
+    fn all_around_neightbor_clockwise_test() {
+        let neigh = AllAroundNeighbor {
+            upleft: Some(&1),
+            up: Some(&2),
+            upright: None,
+            left: Some(&3),
+            right: Some(&4),
+            downleft: None,
+            down: Some(&5),
+            downright: Some(&6),
+        };
+
+        let clockwise: Vec<_> = neigh.iter_clockwise().collect();
+        assert_eq!(
+            clockwise,
+            vec![
+                Some(&2),
+                None,
+                Some(&4),
+                Some(&6),
+                Some(&5),
+                None,
+                Some(&3),
+                Some(&1)
+            ]
+        );
+    }
 }